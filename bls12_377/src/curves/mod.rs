@@ -1,7 +1,10 @@
+use ark_curve_utils::pairing;
 use ark_ec::{
     bls12,
     bls12::{Bls12, Bls12Config, TwistType},
+    pairing::PairingOutput,
 };
+use ark_serialize::{Compress, Read, SerializationError};
 
 use crate::*;
 
@@ -37,3 +40,20 @@ pub type G2Affine = bls12::G2Affine<Config>;
 pub type G2Projective = bls12::G2Projective<Config>;
 
 pub use g1::{G1TEAffine, G1TEProjective};
+
+/// Checks whether `f` lies in the order-`r` subgroup of `Fq12^*` that contains
+/// the pairing outputs with `ark_curve_utils::pairing::is_in_gt`, as
+/// `q = X (mod r)`.
+pub fn is_in_gt(f: &Fq12) -> bool {
+    pairing::is_in_gt(f, Config::X, Config::X_IS_NEGATIVE)
+}
+
+/// Deserializes a pairing output, checking that it lies in GT with
+/// [`is_in_gt`] rather than with the exponentiation by `r` of
+/// `Validate::Yes`.
+pub fn deserialize_pairing_output<R: Read>(
+    reader: R,
+    compress: Compress,
+) -> Result<PairingOutput<Bls12_377>, SerializationError> {
+    pairing::deserialize_pairing_output(reader, compress, Config::X, Config::X_IS_NEGATIVE)
+}
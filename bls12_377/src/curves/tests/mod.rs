@@ -1,5 +1,13 @@
-use crate::{Bls12_377, G1Projective, G2Projective};
+use crate::{
+    deserialize_pairing_output, is_in_gt, Bls12_377, Fq, Fr, G1Affine, G1Projective, G2Affine,
+    G2Projective,
+};
 use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::groups::gt_membership_test;
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+use ark_ff::{BigInteger, One, PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{format, rand::Rng, test_rng, vec, vec::Vec};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
@@ -57,3 +65,254 @@ mod test {
         wb_hash_arbitrary_string_to_curve::<crate::g2::Config>();
     }
 }
+
+#[test]
+fn test_gt_membership() {
+    gt_membership_test::<Bls12_377>(is_in_gt, |bytes, compress| {
+        deserialize_pairing_output(bytes, compress)
+    });
+}
+
+/// Encodes a point with flag bits set as in the Zcash specification, computed
+/// directly from the big-endian coordinates rather than with
+/// `ark_curve_utils::serialization`. The coordinates are given with the most
+/// significant component first, i.e. `[c1, c0]` for `Fq2`.
+fn reference_encoding(x: &[Fq], y: &[Fq], infinity: bool, compress: bool) -> Vec<u8> {
+    let mut bytes = vec![];
+    let coordinates = if compress { vec![x] } else { vec![x, y] };
+    for c in coordinates.into_iter().flatten() {
+        let c = if infinity { Fq::zero() } else { *c };
+        bytes.extend_from_slice(&c.into_bigint().to_bytes_be());
+    }
+    let y_bigint: Vec<_> = y.iter().map(|c| c.into_bigint()).collect();
+    let neg_y_bigint: Vec<_> = y.iter().map(|c| (-*c).into_bigint()).collect();
+    if compress {
+        bytes[0] |= 0x80;
+    }
+    if infinity {
+        bytes[0] |= 0x40;
+    } else if compress && y_bigint > neg_y_bigint {
+        bytes[0] |= 0x20;
+    }
+    bytes
+}
+
+fn g1_reference_encoding(p: &G1Affine, compress: Compress) -> Vec<u8> {
+    reference_encoding(&[p.x], &[p.y], p.infinity, compress == Compress::Yes)
+}
+
+fn g2_reference_encoding(p: &G2Affine, compress: Compress) -> Vec<u8> {
+    reference_encoding(
+        &[p.x.c1, p.x.c0],
+        &[p.y.c1, p.y.c0],
+        p.infinity,
+        compress == Compress::Yes,
+    )
+}
+
+/// The generators of EIP-2539, encoded by hand following the Zcash
+/// specification. No third-party test vectors for this encoding of BLS12-377
+/// points are available, so these and [`reference_encoding`] stand in for them.
+#[test]
+fn test_generator_encoding() {
+    let g1_x = "008848defe740a67c8fc6225bf87ff5485951e2caa9d41bb188282c8bd37cb5cd5481512ffcd394eeab9b16eb21be9ef";
+    let g1_y = "01914a69c5102eff1f674f5d30afeec4bd7fb348ca3e52d96d182ad44fb82305c2fe3d3634a9591afd82de55559c8ea6";
+    let g2_x = "00ea6040e700403170dc5a51b1b140d5532777ee6651cecbe7223ece0799c9de5cf89984bff76fe6b26bfefa6ea16afe018480be71c785fec89630a2a3841d01c565f071203e50317ea501f557db6b9b71889f52bb53540274e3e48f7c005196";
+    let g2_y = "00f8169fd28355189e549da3151a70aa61ef11ac3d591bf12463b01acee304c24279b83f5e52270bd9a1cdd185eb8f9300690d665d446f7bd960736bcbb2efb4de03ed7274b49a58e458c282f832d204f2cf88886d8c7c2ef094094409fd4ddf";
+
+    // Both generators have the lexicographically largest `y`.
+    let vectors = [
+        (
+            Compress::Yes,
+            format!("a0{}", &g1_x[2..]),
+            format!("a0{}", &g2_x[2..]),
+        ),
+        (
+            Compress::No,
+            format!("{g1_x}{g1_y}"),
+            format!("{g2_x}{g2_y}"),
+        ),
+    ];
+    for (compress, g1_hex, g2_hex) in vectors {
+        let g1_bytes = hex::decode(g1_hex).unwrap();
+        let g2_bytes = hex::decode(g2_hex).unwrap();
+
+        let mut bytes = vec![];
+        G1Affine::generator()
+            .serialize_with_mode(&mut bytes, compress)
+            .unwrap();
+        assert_eq!(bytes, g1_bytes);
+        let decoded = G1Affine::deserialize_with_mode(&g1_bytes[..], compress, Validate::Yes);
+        assert_eq!(decoded.unwrap(), G1Affine::generator());
+
+        let mut bytes = vec![];
+        G2Affine::generator()
+            .serialize_with_mode(&mut bytes, compress)
+            .unwrap();
+        assert_eq!(bytes, g2_bytes);
+        let decoded = G2Affine::deserialize_with_mode(&g2_bytes[..], compress, Validate::Yes);
+        assert_eq!(decoded.unwrap(), G2Affine::generator());
+    }
+}
+
+#[test]
+fn test_encoding_matches_reference() {
+    for compress in [Compress::Yes, Compress::No] {
+        let mut p = G1Projective::zero();
+        let mut q = G2Projective::zero();
+        for _ in 0..100 {
+            let p_affine = p.into_affine();
+            let expected = g1_reference_encoding(&p_affine, compress);
+            let mut bytes = vec![];
+            p_affine.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes, expected);
+            let decoded = G1Affine::deserialize_with_mode(&expected[..], compress, Validate::Yes);
+            assert_eq!(decoded.unwrap(), p_affine);
+
+            let q_affine = q.into_affine();
+            let expected = g2_reference_encoding(&q_affine, compress);
+            let mut bytes = vec![];
+            q_affine.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes, expected);
+            let decoded = G2Affine::deserialize_with_mode(&expected[..], compress, Validate::Yes);
+            assert_eq!(decoded.unwrap(), q_affine);
+
+            p += G1Projective::generator();
+            q += G2Projective::generator();
+        }
+    }
+}
+
+#[test]
+fn test_infinity_encoding() {
+    let mut bytes = vec![];
+    G1Affine::zero()
+        .serialize_with_mode(&mut bytes, Compress::Yes)
+        .unwrap();
+    assert_eq!(bytes[0], 0xc0);
+    assert!(bytes[1..].iter().all(|&b| b == 0));
+
+    let mut bytes = vec![];
+    G2Affine::zero()
+        .serialize_with_mode(&mut bytes, Compress::No)
+        .unwrap();
+    assert_eq!(bytes[0], 0x40);
+    assert!(bytes[1..].iter().all(|&b| b == 0));
+}
+
+fn assert_g1_rejected(bytes: &[u8], compress: Compress) {
+    assert!(G1Affine::deserialize_with_mode(bytes, compress, Validate::Yes).is_err());
+}
+
+fn assert_g2_rejected(bytes: &[u8], compress: Compress) {
+    assert!(G2Affine::deserialize_with_mode(bytes, compress, Validate::Yes).is_err());
+}
+
+#[test]
+fn test_malformed_flags_are_rejected() {
+    let mut rng = test_rng();
+    let p = G1Projective::rand(&mut rng).into_affine();
+    let q = G2Projective::rand(&mut rng).into_affine();
+
+    let mut p_compressed = vec![];
+    p.serialize_with_mode(&mut p_compressed, Compress::Yes)
+        .unwrap();
+    let mut p_uncompressed = vec![];
+    p.serialize_with_mode(&mut p_uncompressed, Compress::No)
+        .unwrap();
+    let mut q_compressed = vec![];
+    q.serialize_with_mode(&mut q_compressed, Compress::Yes)
+        .unwrap();
+    let mut q_uncompressed = vec![];
+    q.serialize_with_mode(&mut q_uncompressed, Compress::No)
+        .unwrap();
+
+    // Compression flag that does not match the expected encoding.
+    let mut bytes = p_compressed.clone();
+    bytes[0] &= 0x7f;
+    assert_g1_rejected(&bytes, Compress::Yes);
+    let mut bytes = p_uncompressed.clone();
+    bytes[0] |= 0x80;
+    assert_g1_rejected(&bytes, Compress::No);
+    let mut bytes = q_compressed.clone();
+    bytes[0] &= 0x7f;
+    assert_g2_rejected(&bytes, Compress::Yes);
+    let mut bytes = q_uncompressed.clone();
+    bytes[0] |= 0x80;
+    assert_g2_rejected(&bytes, Compress::No);
+
+    // Sort flag on an uncompressed point.
+    let mut bytes = p_uncompressed.clone();
+    bytes[0] |= 0x20;
+    assert_g1_rejected(&bytes, Compress::No);
+    let mut bytes = q_uncompressed.clone();
+    bytes[0] |= 0x20;
+    assert_g2_rejected(&bytes, Compress::No);
+
+    // Infinity flag together with the sort flag, or with nonzero coordinates.
+    let mut bytes = vec![0u8; 48];
+    bytes[0] = 0xe0;
+    assert_g1_rejected(&bytes, Compress::Yes);
+    let mut bytes = vec![0u8; 96];
+    bytes[0] = 0xe0;
+    assert_g2_rejected(&bytes, Compress::Yes);
+    let mut bytes = p_compressed.clone();
+    bytes[0] |= 0x40;
+    assert_g1_rejected(&bytes, Compress::Yes);
+    let mut bytes = q_uncompressed.clone();
+    bytes[0] |= 0x40;
+    assert_g2_rejected(&bytes, Compress::No);
+}
+
+#[test]
+fn test_invalid_points_are_rejected() {
+    let mut rng = test_rng();
+
+    // An x-coordinate that is not reduced modulo the base field modulus.
+    let mut bytes = [0u8; 48];
+    let modulus = Fq::MODULUS.to_bytes_be();
+    bytes.copy_from_slice(&modulus);
+    bytes[0] |= 0x80;
+    assert_g1_rejected(&bytes, Compress::Yes);
+
+    // An x-coordinate for which there is no point on the curve.
+    loop {
+        let x = Fq::rand(&mut rng);
+        if G1Affine::get_point_from_x_unchecked(x, false).is_none() {
+            let mut bytes = vec![];
+            G1Affine::new_unchecked(x, Fq::zero())
+                .serialize_with_mode(&mut bytes, Compress::Yes)
+                .unwrap();
+            assert_g1_rejected(&bytes, Compress::Yes);
+            break;
+        }
+    }
+
+    // A point that is not on the curve.
+    let p = G1Projective::rand(&mut rng).into_affine();
+    let mut bytes = vec![];
+    G1Affine::new_unchecked(p.x, p.y + Fq::one())
+        .serialize_with_mode(&mut bytes, Compress::No)
+        .unwrap();
+    assert_g1_rejected(&bytes, Compress::No);
+
+    // A point on the curve, but outside the prime-order subgroup.
+    loop {
+        let x = Fq::rand(&mut rng);
+        if let Some(p) = G1Affine::get_point_from_x_unchecked(x, rng.gen()) {
+            if !p.mul_bigint(Fr::MODULUS).is_zero() {
+                for compress in [Compress::Yes, Compress::No] {
+                    let mut bytes = vec![];
+                    p.serialize_with_mode(&mut bytes, compress).unwrap();
+                    assert_g1_rejected(&bytes, compress);
+                    assert_eq!(
+                        G1Affine::deserialize_with_mode(&bytes[..], compress, Validate::No)
+                            .unwrap(),
+                        p
+                    );
+                }
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Encoding of BLS12-381 points and the precompiles of [EIP-2537].
+//!
+//! A base field element is encoded as a 64-byte big-endian integer whose top
+//! 16 bytes must be zero and whose value must be smaller than the modulus. An
+//! `Fq2` element `c0 + c1 * u` is encoded as `c0 || c1`. A point is encoded as
+//! `x || y`, and the point at infinity as all zeros. Scalars are 32-byte
+//! big-endian integers that need not be reduced.
+//!
+//! [EIP-2537]: https://eips.ethereum.org/EIPS/eip-2537
+
+use ark_curve_utils::serialization::{deserialize_fq, serialize_fq};
+use ark_ec::{
+    hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurve},
+    pairing::Pairing,
+    AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_ff::{One, PrimeField, Zero};
+use ark_std::{fmt, vec::Vec};
+
+use crate::{g1, g2, Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+/// Size in bytes of an encoded base field element.
+pub const FQ_ENCODED_SIZE: usize = 64;
+/// Size in bytes of an encoded `Fq2` element.
+pub const FQ2_ENCODED_SIZE: usize = 2 * FQ_ENCODED_SIZE;
+/// Size in bytes of an encoded `G1` point.
+pub const G1_ENCODED_SIZE: usize = 2 * FQ_ENCODED_SIZE;
+/// Size in bytes of an encoded `G2` point.
+pub const G2_ENCODED_SIZE: usize = 2 * FQ2_ENCODED_SIZE;
+/// Size in bytes of an encoded scalar.
+pub const SCALAR_ENCODED_SIZE: usize = 32;
+
+/// Size in bytes of a single (point, scalar) pair in the input of `G1MSM`.
+pub const G1_MSM_PAIR_SIZE: usize = G1_ENCODED_SIZE + SCALAR_ENCODED_SIZE;
+/// Size in bytes of a single (point, scalar) pair in the input of `G2MSM`.
+pub const G2_MSM_PAIR_SIZE: usize = G2_ENCODED_SIZE + SCALAR_ENCODED_SIZE;
+/// Size in bytes of a single (`G1`, `G2`) pair in the input of
+/// `PAIRING_CHECK`.
+pub const PAIRING_PAIR_SIZE: usize = G1_ENCODED_SIZE + G2_ENCODED_SIZE;
+
+/// Number of leading bytes of an encoded field element that must be zero.
+const FQ_PADDING_SIZE: usize = FQ_ENCODED_SIZE - 48;
+
+/// Errors returned when decoding inputs or running the precompiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input has a length not accepted by the precompile.
+    InvalidInputLength,
+    /// An encoded field element has nonzero padding or is not smaller than
+    /// the modulus.
+    InvalidFieldElement,
+    /// The decoded point does not satisfy the curve equation.
+    NotOnCurve,
+    /// The decoded point is not in the prime-order subgroup.
+    NotInSubgroup,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInputLength => write!(f, "invalid input length"),
+            Error::InvalidFieldElement => write!(f, "invalid field element"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::NotInSubgroup => write!(f, "point is not in the prime-order subgroup"),
+        }
+    }
+}
+
+/// Encodes a base field element as 64 big-endian bytes.
+pub fn encode_fq(f: &Fq) -> [u8; FQ_ENCODED_SIZE] {
+    let mut bytes = [0u8; FQ_ENCODED_SIZE];
+    bytes[FQ_PADDING_SIZE..].copy_from_slice(&serialize_fq(*f));
+    bytes
+}
+
+/// Decodes a base field element encoded by [`encode_fq`].
+pub fn decode_fq(bytes: &[u8; FQ_ENCODED_SIZE]) -> Result<Fq, Error> {
+    if bytes[..FQ_PADDING_SIZE].iter().any(|&b| b != 0) {
+        return Err(Error::InvalidFieldElement);
+    }
+    deserialize_fq(bytes[FQ_PADDING_SIZE..].try_into().unwrap()).ok_or(Error::InvalidFieldElement)
+}
+
+/// Encodes an `Fq2` element `c0 + c1 * u` as `c0 || c1`.
+pub fn encode_fq2(f: &Fq2) -> [u8; FQ2_ENCODED_SIZE] {
+    let mut bytes = [0u8; FQ2_ENCODED_SIZE];
+    bytes[..FQ_ENCODED_SIZE].copy_from_slice(&encode_fq(&f.c0));
+    bytes[FQ_ENCODED_SIZE..].copy_from_slice(&encode_fq(&f.c1));
+    bytes
+}
+
+/// Decodes an `Fq2` element encoded by [`encode_fq2`].
+pub fn decode_fq2(bytes: &[u8; FQ2_ENCODED_SIZE]) -> Result<Fq2, Error> {
+    let c0 = decode_fq(bytes[..FQ_ENCODED_SIZE].try_into().unwrap())?;
+    let c1 = decode_fq(bytes[FQ_ENCODED_SIZE..].try_into().unwrap())?;
+    Ok(Fq2::new(c0, c1))
+}
+
+/// Encodes a `G1` point as `x || y`, with infinity encoded as all zeros.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_ENCODED_SIZE] {
+    let mut bytes = [0u8; G1_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        bytes[..FQ_ENCODED_SIZE].copy_from_slice(&encode_fq(&x));
+        bytes[FQ_ENCODED_SIZE..].copy_from_slice(&encode_fq(&y));
+    }
+    bytes
+}
+
+/// Decodes a `G1` point that is on the curve, but not necessarily in the
+/// prime-order subgroup.
+fn decode_g1_on_curve(bytes: &[u8; G1_ENCODED_SIZE]) -> Result<G1Affine, Error> {
+    let x = decode_fq(bytes[..FQ_ENCODED_SIZE].try_into().unwrap())?;
+    let y = decode_fq(bytes[FQ_ENCODED_SIZE..].try_into().unwrap())?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    let p = G1Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    Ok(p)
+}
+
+/// Decodes a `G1` point encoded by [`encode_g1`], checking that it is on the
+/// curve and in the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8; G1_ENCODED_SIZE]) -> Result<G1Affine, Error> {
+    let p = decode_g1_on_curve(bytes)?;
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
+
+/// Encodes a `G2` point as `x || y`, with infinity encoded as all zeros.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_ENCODED_SIZE] {
+    let mut bytes = [0u8; G2_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        bytes[..FQ2_ENCODED_SIZE].copy_from_slice(&encode_fq2(&x));
+        bytes[FQ2_ENCODED_SIZE..].copy_from_slice(&encode_fq2(&y));
+    }
+    bytes
+}
+
+/// Decodes a `G2` point that is on the curve, but not necessarily in the
+/// prime-order subgroup.
+fn decode_g2_on_curve(bytes: &[u8; G2_ENCODED_SIZE]) -> Result<G2Affine, Error> {
+    let x = decode_fq2(bytes[..FQ2_ENCODED_SIZE].try_into().unwrap())?;
+    let y = decode_fq2(bytes[FQ2_ENCODED_SIZE..].try_into().unwrap())?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    let p = G2Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    Ok(p)
+}
+
+/// Decodes a `G2` point encoded by [`encode_g2`], checking that it is on the
+/// curve and in the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8; G2_ENCODED_SIZE]) -> Result<G2Affine, Error> {
+    let p = decode_g2_on_curve(bytes)?;
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
+
+/// Decodes a scalar, reducing it modulo `r`. This is only sound for points in
+/// the prime-order subgroup.
+fn decode_scalar(bytes: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(bytes)
+}
+
+/// Splits `input` into chunks of `N` bytes, requiring at least one chunk and
+/// no trailing bytes.
+fn split_input<const N: usize>(input: &[u8]) -> Result<impl Iterator<Item = &[u8]>, Error> {
+    if input.is_empty() || input.len() % N != 0 {
+        return Err(Error::InvalidInputLength);
+    }
+    Ok(input.chunks_exact(N))
+}
+
+/// The `G1ADD` precompile (address `0x0b`).
+///
+/// Both points must be on the curve, but are not required to be in the
+/// prime-order subgroup.
+pub fn g1_add(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    if input.len() != 2 * G1_ENCODED_SIZE {
+        return Err(Error::InvalidInputLength);
+    }
+    let a = decode_g1_on_curve(input[..G1_ENCODED_SIZE].try_into().unwrap())?;
+    let b = decode_g1_on_curve(input[G1_ENCODED_SIZE..].try_into().unwrap())?;
+    Ok(encode_g1(&(a + b).into_affine()))
+}
+
+/// The `G1MSM` precompile (address `0x0c`).
+///
+/// The input is a nonempty list of (point, scalar) pairs, and all points must
+/// be in the prime-order subgroup.
+pub fn g1_msm(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let mut bases = Vec::with_capacity(input.len() / G1_MSM_PAIR_SIZE);
+    let mut scalars = Vec::with_capacity(input.len() / G1_MSM_PAIR_SIZE);
+    for pair in split_input::<G1_MSM_PAIR_SIZE>(input)? {
+        bases.push(decode_g1(pair[..G1_ENCODED_SIZE].try_into().unwrap())?);
+        scalars.push(decode_scalar(&pair[G1_ENCODED_SIZE..]));
+    }
+    Ok(encode_g1(
+        &G1Projective::msm_unchecked(&bases, &scalars).into_affine(),
+    ))
+}
+
+/// The `G2ADD` precompile (address `0x0d`).
+///
+/// Both points must be on the curve, but are not required to be in the
+/// prime-order subgroup.
+pub fn g2_add(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    if input.len() != 2 * G2_ENCODED_SIZE {
+        return Err(Error::InvalidInputLength);
+    }
+    let a = decode_g2_on_curve(input[..G2_ENCODED_SIZE].try_into().unwrap())?;
+    let b = decode_g2_on_curve(input[G2_ENCODED_SIZE..].try_into().unwrap())?;
+    Ok(encode_g2(&(a + b).into_affine()))
+}
+
+/// The `G2MSM` precompile (address `0x0e`).
+///
+/// The input is a nonempty list of (point, scalar) pairs, and all points must
+/// be in the prime-order subgroup.
+pub fn g2_msm(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    let mut bases = Vec::with_capacity(input.len() / G2_MSM_PAIR_SIZE);
+    let mut scalars = Vec::with_capacity(input.len() / G2_MSM_PAIR_SIZE);
+    for pair in split_input::<G2_MSM_PAIR_SIZE>(input)? {
+        bases.push(decode_g2(pair[..G2_ENCODED_SIZE].try_into().unwrap())?);
+        scalars.push(decode_scalar(&pair[G2_ENCODED_SIZE..]));
+    }
+    Ok(encode_g2(
+        &G2Projective::msm_unchecked(&bases, &scalars).into_affine(),
+    ))
+}
+
+/// The `PAIRING_CHECK` precompile (address `0x0f`).
+///
+/// The input is a nonempty list of (`G1`, `G2`) pairs in the prime-order
+/// subgroups, and the output is the 32-byte big-endian encoding of `1` if the
+/// product of their pairings is the identity, and of `0` otherwise.
+pub fn pairing_check(input: &[u8]) -> Result<[u8; 32], Error> {
+    let mut g1 = Vec::with_capacity(input.len() / PAIRING_PAIR_SIZE);
+    let mut g2 = Vec::with_capacity(input.len() / PAIRING_PAIR_SIZE);
+    for pair in split_input::<PAIRING_PAIR_SIZE>(input)? {
+        g1.push(decode_g1(pair[..G1_ENCODED_SIZE].try_into().unwrap())?);
+        g2.push(decode_g2(pair[G1_ENCODED_SIZE..].try_into().unwrap())?);
+    }
+    let mut output = [0u8; 32];
+    if Bls12_381::multi_pairing(g1, g2).0.is_one() {
+        output[31] = 1;
+    }
+    Ok(output)
+}
+
+/// The `MAP_FP_TO_G1` precompile (address `0x10`).
+///
+/// Applies the simplified SWU map of the `BLS12381G1_XMD:SHA-256_SSWU_`
+/// suites of RFC 9380 to a field element and clears the cofactor.
+pub fn map_fp_to_g1(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let input: &[u8; FQ_ENCODED_SIZE] = input.try_into().map_err(|_| Error::InvalidInputLength)?;
+    let u = decode_fq(input)?;
+    let p = WBMap::<g1::Config>::map_to_curve(u).expect("the map to G1 is defined everywhere");
+    Ok(encode_g1(&p.clear_cofactor()))
+}
+
+/// The `MAP_FP2_TO_G2` precompile (address `0x11`).
+///
+/// Applies the simplified SWU map of the `BLS12381G2_XMD:SHA-256_SSWU_`
+/// suites of RFC 9380 to an `Fq2` element and clears the cofactor.
+pub fn map_fp2_to_g2(input: &[u8]) -> Result<[u8; G2_ENCODED_SIZE], Error> {
+    let input: &[u8; FQ2_ENCODED_SIZE] = input.try_into().map_err(|_| Error::InvalidInputLength)?;
+    let u = decode_fq2(input)?;
+    let p = WBMap::<g2::Config>::map_to_curve(u).expect("the map to G2 is defined everywhere");
+    Ok(encode_g2(&p.clear_cofactor()))
+}
@@ -1,3 +1,6 @@
+use ark_curve_utils::serialization::{
+    read_g1_compressed, read_g1_uncompressed, serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
+};
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
@@ -12,12 +15,7 @@ use ark_serialize::{Compress, SerializationError};
 use ark_std::{ops::Neg, One};
 
 use super::g1_swu_iso;
-use crate::{
-    util::{
-        read_g1_compressed, read_g1_uncompressed, serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
-    },
-    Fq, Fr,
-};
+use crate::{Fq, Fr};
 
 pub type G1Affine = bls12::G1Affine<crate::Config>;
 pub type G1Projective = bls12::G1Projective<crate::Config>;
@@ -1,5 +1,8 @@
 use ark_std::ops::Neg;
 
+use ark_curve_utils::serialization::{
+    read_g2_compressed, read_g2_uncompressed, serialize_fq, EncodingFlags, G2_SERIALIZED_SIZE,
+};
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
@@ -12,14 +15,8 @@ use ark_ec::{
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 use ark_serialize::{Compress, SerializationError};
 
-use super::{
-    g2_swu_iso,
-    util::{serialize_fq, EncodingFlags, G2_SERIALIZED_SIZE},
-};
-use crate::{
-    util::{read_g2_compressed, read_g2_uncompressed},
-    *,
-};
+use super::g2_swu_iso;
+use crate::*;
 
 pub type G2Affine = bls12::G2Affine<crate::Config>;
 pub type G2Projective = bls12::G2Projective<crate::Config>;
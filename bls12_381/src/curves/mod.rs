@@ -1,10 +1,16 @@
-use ark_ec::bls12::{Bls12, Bls12Config, TwistType};
+use ark_curve_utils::pairing;
+use ark_ec::{
+    bls12::{Bls12, Bls12Config, TwistType},
+    pairing::PairingOutput,
+};
+use ark_serialize::{Compress, Read, SerializationError};
 
-use crate::{Fq, Fq12Config, Fq2Config, Fq6Config};
+use crate::{Fq, Fq12, Fq12Config, Fq2Config, Fq6Config};
 
+pub mod eip2537;
 pub mod g1;
 pub mod g2;
-pub(crate) mod util;
+pub mod signature;
 
 mod g1_swu_iso;
 mod g2_swu_iso;
@@ -32,3 +38,20 @@ impl Bls12Config for Config {
     type G1Config = self::g1::Config;
     type G2Config = self::g2::Config;
 }
+
+/// Checks whether `f` lies in the order-`r` subgroup of `Fq12^*` that contains
+/// the pairing outputs with `ark_curve_utils::pairing::is_in_gt`, as
+/// `q = X (mod r)`.
+pub fn is_in_gt(f: &Fq12) -> bool {
+    pairing::is_in_gt(f, Config::X, Config::X_IS_NEGATIVE)
+}
+
+/// Deserializes a pairing output, checking that it lies in GT with
+/// [`is_in_gt`] rather than with the exponentiation by `r` of
+/// `Validate::Yes`.
+pub fn deserialize_pairing_output<R: Read>(
+    reader: R,
+    compress: Compress,
+) -> Result<PairingOutput<Bls12_381>, SerializationError> {
+    pairing::deserialize_pairing_output(reader, compress, Config::X, Config::X_IS_NEGATIVE)
+}
@@ -0,0 +1,438 @@
+//! BLS signatures of [draft-irtf-cfrg-bls-signature-05].
+//!
+//! A [`Variant`] chooses the groups of public keys and signatures: [`MinPk`]
+//! has public keys in `G1` and signatures in `G2`, and [`MinSig`] the
+//! reverse. A [`Scheme`] chooses how rogue key attacks on aggregate
+//! signatures are prevented. Together they form a [`Ciphersuite`], and the
+//! six ciphersuites of section 4.2 are provided as constants, such as
+//! [`G2_POP`] for `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`, which is the
+//! ciphersuite used by Ethereum.
+//!
+//! Messages are hashed to the signature group with the
+//! `BLS12381G1_XMD:SHA-256_SSWU_RO_` or `BLS12381G2_XMD:SHA-256_SSWU_RO_`
+//! suite of RFC 9380. Points are encoded in the compressed form of their
+//! `CanonicalSerialize` implementations, of 48 bytes in `G1` and 96 bytes in
+//! `G2`. Secret keys are nonzero scalars.
+//!
+//! [draft-irtf-cfrg-bls-signature-05]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05
+
+use ark_ec::{
+    hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+    pairing::Pairing,
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, One, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{fmt, marker::PhantomData, vec::Vec};
+use sha2::Sha256;
+
+use crate::{g1, g2, Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+
+/// Errors returned when signing, decoding or verifying.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The secret key is zero.
+    InvalidSecretKey,
+    /// The public key is malformed, the point at infinity, or not in the
+    /// prime-order subgroup.
+    InvalidPublicKey,
+    /// The signature is malformed, not in the prime-order subgroup, or does
+    /// not verify.
+    InvalidSignature,
+    /// No signatures or public keys were given.
+    EmptyInput,
+    /// The same message appears twice in an aggregate of the basic scheme.
+    DuplicateMessage,
+    /// The operation is only defined for the proof of possession scheme.
+    UnsupportedScheme,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSecretKey => write!(f, "invalid secret key"),
+            Error::InvalidPublicKey => write!(f, "invalid public key"),
+            Error::InvalidSignature => write!(f, "invalid signature"),
+            Error::EmptyInput => write!(f, "empty input"),
+            Error::DuplicateMessage => write!(f, "duplicate message"),
+            Error::UnsupportedScheme => write!(f, "operation not supported by scheme"),
+        }
+    }
+}
+
+mod sealed {
+    use super::{PublicKey, Signature, Variant};
+
+    /// Keeps [`Variant`] from being implemented, and the pairing check from
+    /// being called, outside of this module.
+    pub trait Sealed {
+        /// Returns whether the product of the pairings of `public[i]` and
+        /// `signature[i]` is the identity.
+        fn multi_pairing_is_one(public: &[PublicKey<Self>], signature: &[Signature<Self>]) -> bool
+        where
+            Self: Variant;
+    }
+}
+
+/// The groups of public keys and signatures, and how messages are hashed to
+/// the signature group.
+///
+/// This trait is sealed: it is implemented by [`MinPk`] and [`MinSig`] only.
+pub trait Variant: sealed::Sealed {
+    /// The curve of public keys.
+    type PublicKeyConfig: SWCurveConfig<ScalarField = Fr>;
+    /// The curve of signatures.
+    type SignatureConfig: SWCurveConfig<ScalarField = Fr>;
+
+    /// The name of the signature group in ciphersuite IDs.
+    const SIGNATURE_GROUP: &'static [u8];
+
+    /// Hashes `message` to the signature group with the domain separation
+    /// tag `dst`.
+    fn hash_to_signature_group(message: &[u8], dst: &[u8]) -> Signature<Self>;
+}
+
+/// The `hash_to_curve` function of the `_XMD:SHA-256_SSWU_RO_` suites of
+/// RFC 9380.
+type Hasher<T, M> = MapToCurveBasedHasher<T, DefaultFieldHasher<Sha256, 128>, M>;
+
+/// A public key of the variant `V`.
+pub type PublicKey<V> = Affine<<V as Variant>::PublicKeyConfig>;
+/// A signature of the variant `V`.
+pub type Signature<V> = Affine<<V as Variant>::SignatureConfig>;
+
+/// Public keys in `G1` and signatures in `G2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinPk;
+
+impl Variant for MinPk {
+    type PublicKeyConfig = g1::Config;
+    type SignatureConfig = g2::Config;
+
+    const SIGNATURE_GROUP: &'static [u8] = b"BLS12381G2";
+
+    fn hash_to_signature_group(message: &[u8], dst: &[u8]) -> G2Affine {
+        Hasher::<G2Projective, WBMap<g2::Config>>::new(dst)
+            .and_then(|hasher| hasher.hash(message))
+            .expect("the domain separation tag is shorter than 256 bytes")
+    }
+}
+
+impl sealed::Sealed for MinPk {
+    fn multi_pairing_is_one(public: &[G1Affine], signature: &[G2Affine]) -> bool {
+        Bls12_381::multi_pairing(public, signature).0.is_one()
+    }
+}
+
+/// Public keys in `G2` and signatures in `G1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinSig;
+
+impl Variant for MinSig {
+    type PublicKeyConfig = g2::Config;
+    type SignatureConfig = g1::Config;
+
+    const SIGNATURE_GROUP: &'static [u8] = b"BLS12381G1";
+
+    fn hash_to_signature_group(message: &[u8], dst: &[u8]) -> G1Affine {
+        Hasher::<G1Projective, WBMap<g1::Config>>::new(dst)
+            .and_then(|hasher| hasher.hash(message))
+            .expect("the domain separation tag is shorter than 256 bytes")
+    }
+}
+
+impl sealed::Sealed for MinSig {
+    fn multi_pairing_is_one(public: &[G2Affine], signature: &[G1Affine]) -> bool {
+        Bls12_381::multi_pairing(signature, public).0.is_one()
+    }
+}
+
+/// The scheme used to prevent rogue key attacks, see section 3 of the draft.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    /// The basic scheme, which requires the messages of an aggregate
+    /// signature to be distinct.
+    Basic,
+    /// Message augmentation, which prepends the public key to each message.
+    MessageAugmentation,
+    /// Proof of possession, which requires each public key to come with a
+    /// proof that its owner knows the secret key, and allows signatures on
+    /// the same message to be verified with [`Ciphersuite::fast_aggregate_verify`].
+    ProofOfPossession,
+}
+
+impl Scheme {
+    /// The tag of the scheme in ciphersuite IDs.
+    fn tag(&self) -> &'static [u8] {
+        match self {
+            Scheme::Basic => b"NUL",
+            Scheme::MessageAugmentation => b"AUG",
+            Scheme::ProofOfPossession => b"POP",
+        }
+    }
+}
+
+/// A ciphersuite, consisting of a [`Variant`] and a [`Scheme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ciphersuite<V: Variant> {
+    scheme: Scheme,
+    _variant: PhantomData<V>,
+}
+
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_`
+pub const G2_BASIC: Ciphersuite<MinPk> = Ciphersuite::new(Scheme::Basic);
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_`
+pub const G2_AUG: Ciphersuite<MinPk> = Ciphersuite::new(Scheme::MessageAugmentation);
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`
+pub const G2_POP: Ciphersuite<MinPk> = Ciphersuite::new(Scheme::ProofOfPossession);
+/// `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_`
+pub const G1_BASIC: Ciphersuite<MinSig> = Ciphersuite::new(Scheme::Basic);
+/// `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_`
+pub const G1_AUG: Ciphersuite<MinSig> = Ciphersuite::new(Scheme::MessageAugmentation);
+/// `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_`
+pub const G1_POP: Ciphersuite<MinSig> = Ciphersuite::new(Scheme::ProofOfPossession);
+
+/// Builds the tag `BLS_<kind>_<group>_XMD:SHA-256_SSWU_RO_<scheme>_`.
+fn domain_separation_tag(kind: &[u8], group: &[u8], scheme: &[u8]) -> Vec<u8> {
+    [
+        b"BLS_",
+        kind,
+        b"_",
+        group,
+        b"_XMD:SHA-256_SSWU_RO_",
+        scheme,
+        b"_",
+    ]
+    .concat()
+}
+
+/// Encodes a point in compressed form.
+pub fn encode_point<P: AffineRepr>(p: &P) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(p.compressed_size());
+    p.serialize_compressed(&mut bytes)
+        .expect("serializing into a vector cannot fail");
+    bytes
+}
+
+/// Returns whether `p` is in the prime-order subgroup.
+fn is_in_subgroup<P: SWCurveConfig>(p: &Affine<P>) -> bool {
+    p.is_in_correct_subgroup_assuming_on_curve()
+}
+
+impl<V: Variant> Ciphersuite<V> {
+    /// Returns the ciphersuite of `V` with `scheme`.
+    pub const fn new(scheme: Scheme) -> Self {
+        Self {
+            scheme,
+            _variant: PhantomData,
+        }
+    }
+
+    /// Returns the scheme of the ciphersuite.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns the ciphersuite ID, which is the domain separation tag used
+    /// to hash messages.
+    pub fn id(&self) -> Vec<u8> {
+        domain_separation_tag(b"SIG", V::SIGNATURE_GROUP, self.scheme.tag())
+    }
+
+    /// Returns the domain separation tag used to hash public keys into
+    /// proofs of possession.
+    pub fn pop_id(&self) -> Vec<u8> {
+        domain_separation_tag(b"POP", V::SIGNATURE_GROUP, self.scheme.tag())
+    }
+
+    /// The `SkToPk` function: returns the public key of `secret`.
+    pub fn sk_to_pk(&self, secret: &Fr) -> Result<PublicKey<V>, Error> {
+        if secret.is_zero() {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok((PublicKey::<V>::generator() * secret).into_affine())
+    }
+
+    /// The `KeyValidate` function: checks that `public` is in the
+    /// prime-order subgroup and is not the point at infinity.
+    pub fn key_validate(&self, public: &PublicKey<V>) -> Result<(), Error> {
+        if public.is_zero() || !is_in_subgroup(public) {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(())
+    }
+
+    /// Decodes a compressed public key and validates it with
+    /// [`Self::key_validate`].
+    pub fn decode_public_key(&self, bytes: &[u8]) -> Result<PublicKey<V>, Error> {
+        let public = PublicKey::<V>::deserialize_with_mode(bytes, Compress::Yes, Validate::No)
+            .map_err(|_| Error::InvalidPublicKey)?;
+        if bytes.len() != public.compressed_size() {
+            return Err(Error::InvalidPublicKey);
+        }
+        self.key_validate(&public)?;
+        Ok(public)
+    }
+
+    /// Decodes a compressed signature, which must be in the prime-order
+    /// subgroup.
+    pub fn decode_signature(&self, bytes: &[u8]) -> Result<Signature<V>, Error> {
+        let signature = Signature::<V>::deserialize_with_mode(bytes, Compress::Yes, Validate::No)
+            .map_err(|_| Error::InvalidSignature)?;
+        if bytes.len() != signature.compressed_size() || !is_in_subgroup(&signature) {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(signature)
+    }
+
+    /// Returns the message that is actually signed: `message` itself, or
+    /// the encoding of `public` followed by `message` with message
+    /// augmentation.
+    fn augment(&self, public: &PublicKey<V>, message: &[u8]) -> Vec<u8> {
+        match self.scheme {
+            Scheme::MessageAugmentation => [&encode_point(public)[..], message].concat(),
+            _ => message.to_vec(),
+        }
+    }
+
+    /// Signs `message` with `secret`.
+    pub fn sign(&self, secret: &Fr, message: &[u8]) -> Result<Signature<V>, Error> {
+        let public = self.sk_to_pk(secret)?;
+        let h = V::hash_to_signature_group(&self.augment(&public, message), &self.id());
+        Ok((h * secret).into_affine())
+    }
+
+    /// Checks that the product of the pairings of each public key with the
+    /// hash of its message, and of the negated generator with `signature`,
+    /// is the identity. All public keys are validated.
+    fn core_aggregate_verify<'a>(
+        &self,
+        items: impl Iterator<Item = (&'a PublicKey<V>, &'a [u8])>,
+        signature: &Signature<V>,
+        dst: &[u8],
+    ) -> Result<(), Error> {
+        if !is_in_subgroup(signature) {
+            return Err(Error::InvalidSignature);
+        }
+        let mut public = Vec::new();
+        let mut points = Vec::new();
+        for (key, message) in items {
+            self.key_validate(key)?;
+            public.push(*key);
+            points.push(V::hash_to_signature_group(message, dst));
+        }
+        if public.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        public.push(-PublicKey::<V>::generator());
+        points.push(*signature);
+
+        if V::multi_pairing_is_one(&public, &points) {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+
+    /// Verifies a signature on `message` with `public`.
+    pub fn verify(
+        &self,
+        public: &PublicKey<V>,
+        message: &[u8],
+        signature: &Signature<V>,
+    ) -> Result<(), Error> {
+        let message = self.augment(public, message);
+        self.core_aggregate_verify([(public, &message[..])].into_iter(), signature, &self.id())
+    }
+
+    /// The `Aggregate` function: adds up a nonempty list of signatures.
+    pub fn aggregate(&self, signatures: &[Signature<V>]) -> Result<Signature<V>, Error> {
+        if signatures.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        if !signatures.iter().all(is_in_subgroup) {
+            return Err(Error::InvalidSignature);
+        }
+        let sum = signatures
+            .iter()
+            .fold(Projective::<V::SignatureConfig>::zero(), |acc, s| acc + s);
+        Ok(sum.into_affine())
+    }
+
+    /// The `AggregateVerify` function: verifies an aggregate of signatures
+    /// on each message with the corresponding public key.
+    ///
+    /// The basic scheme rejects the input if two messages are equal.
+    pub fn aggregate_verify(
+        &self,
+        items: &[(PublicKey<V>, &[u8])],
+        signature: &Signature<V>,
+    ) -> Result<(), Error> {
+        if self.scheme == Scheme::Basic {
+            let mut messages: Vec<&[u8]> = items.iter().map(|(_, m)| *m).collect();
+            messages.sort_unstable();
+            if messages.windows(2).any(|w| w[0] == w[1]) {
+                return Err(Error::DuplicateMessage);
+            }
+        }
+        let messages: Vec<Vec<u8>> = items
+            .iter()
+            .map(|(key, message)| self.augment(key, message))
+            .collect();
+        self.core_aggregate_verify(
+            items
+                .iter()
+                .zip(&messages)
+                .map(|((key, _), m)| (key, &m[..])),
+            signature,
+            &self.id(),
+        )
+    }
+
+    /// The `FastAggregateVerify` function of the proof of possession scheme:
+    /// verifies an aggregate of signatures on the same message by the
+    /// holders of `public`, whose proofs of possession must have been
+    /// checked beforehand.
+    pub fn fast_aggregate_verify(
+        &self,
+        public: &[PublicKey<V>],
+        message: &[u8],
+        signature: &Signature<V>,
+    ) -> Result<(), Error> {
+        if self.scheme != Scheme::ProofOfPossession {
+            return Err(Error::UnsupportedScheme);
+        }
+        if public.is_empty() {
+            return Err(Error::EmptyInput);
+        }
+        for key in public {
+            self.key_validate(key)?;
+        }
+        let sum = public
+            .iter()
+            .fold(Projective::<V::PublicKeyConfig>::zero(), |acc, p| acc + p)
+            .into_affine();
+        self.verify(&sum, message, signature)
+    }
+
+    /// The `PopProve` function: proves possession of `secret`.
+    pub fn pop_prove(&self, secret: &Fr) -> Result<Signature<V>, Error> {
+        if self.scheme != Scheme::ProofOfPossession {
+            return Err(Error::UnsupportedScheme);
+        }
+        let public = encode_point(&self.sk_to_pk(secret)?);
+        let h = V::hash_to_signature_group(&public, &self.pop_id());
+        Ok((h * secret).into_affine())
+    }
+
+    /// The `PopVerify` function: verifies a proof of possession of the
+    /// secret key of `public`.
+    pub fn pop_verify(&self, public: &PublicKey<V>, proof: &Signature<V>) -> Result<(), Error> {
+        if self.scheme != Scheme::ProofOfPossession {
+            return Err(Error::UnsupportedScheme);
+        }
+        let message = encode_point(public);
+        self.core_aggregate_verify([(public, &message[..])].into_iter(), proof, &self.pop_id())
+    }
+}
@@ -1,10 +1,16 @@
 use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::groups::gt_membership_test;
 use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
-use ark_ff::{fields::Field, One, UniformRand, Zero};
+use ark_ff::{fields::Field, One, PrimeField, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
-use ark_std::{rand::Rng, test_rng, vec};
+use ark_std::{rand::Rng, test_rng, vec, vec::Vec};
+use sha2::{Digest, Sha256};
 
-use crate::{Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use crate::{
+    deserialize_pairing_output, eip2537, is_in_gt,
+    signature::{self, encode_point, Ciphersuite, Scheme, G2_POP},
+    Bls12_381, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
@@ -121,3 +127,577 @@ fn g2_uncompressed_valid_test_vectors() {
     let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
     test_vectors!(G2Projective, G2Affine, Compress::No, bytes);
 }
+
+#[test]
+fn test_gt_membership() {
+    gt_membership_test::<Bls12_381>(is_in_gt, |bytes, compress| {
+        deserialize_pairing_output(bytes, compress)
+    });
+}
+
+/// The error of `eip2537` for an `ExpectedError` of the go-ethereum vectors.
+fn eip2537_error(expected: &str) -> eip2537::Error {
+    match expected {
+        "invalid input length" => eip2537::Error::InvalidInputLength,
+        "invalid fp.Element encoding" | "invalid field element top bytes" => {
+            eip2537::Error::InvalidFieldElement
+        },
+        "invalid point: not on curve" => eip2537::Error::NotOnCurve,
+        "g1 point is not on correct subgroup" | "g2 point is not on correct subgroup" => {
+            eip2537::Error::NotInSubgroup
+        },
+        _ => panic!("unknown error: {}", expected),
+    }
+}
+
+/// Checks a precompile against the vectors of go-ethereum in
+/// core/vm/testdata/precompiles, as vendored by revm-precompile 8.0.0. The
+/// `mul_*` vectors of the former G1MUL and G2MUL precompiles are MSMs of a
+/// single pair.
+fn test_eip2537_precompile<const N: usize>(
+    vectors: &str,
+    fail_vectors: &str,
+    precompile: fn(&[u8]) -> Result<[u8; N], eip2537::Error>,
+) {
+    let vectors: Vec<serde_json::Value> = serde_json::from_str(vectors).unwrap();
+    for case in vectors {
+        let input = hex::decode(case["Input"].as_str().unwrap()).unwrap();
+        let expected = hex::decode(case["Expected"].as_str().unwrap()).unwrap();
+        let output = precompile(&input)
+            .unwrap_or_else(|e| panic!("{}: unexpected error: {}", case["Name"], e));
+        assert_eq!(output.as_slice(), &expected[..], "{}", case["Name"]);
+    }
+
+    let fail_vectors: Vec<serde_json::Value> = serde_json::from_str(fail_vectors).unwrap();
+    for case in fail_vectors {
+        let input = hex::decode(case["Input"].as_str().unwrap()).unwrap();
+        let expected = eip2537_error(case["ExpectedError"].as_str().unwrap());
+        assert_eq!(precompile(&input), Err(expected), "{}", case["Name"]);
+    }
+}
+
+macro_rules! test_eip2537 {
+    ($name:ident, $file:literal, $precompile:path) => {
+        #[test]
+        fn $name() {
+            test_eip2537_precompile(
+                include_str!(concat!("eip2537/", $file, ".json")),
+                include_str!(concat!("eip2537/fail-", $file, ".json")),
+                $precompile,
+            );
+        }
+    };
+}
+
+test_eip2537!(test_eip2537_g1_add, "add_G1_bls", eip2537::g1_add);
+test_eip2537!(test_eip2537_g1_mul, "mul_G1_bls", eip2537::g1_msm);
+test_eip2537!(test_eip2537_g1_msm, "multiexp_G1_bls", eip2537::g1_msm);
+test_eip2537!(test_eip2537_g2_add, "add_G2_bls", eip2537::g2_add);
+test_eip2537!(test_eip2537_g2_mul, "mul_G2_bls", eip2537::g2_msm);
+test_eip2537!(test_eip2537_g2_msm, "multiexp_G2_bls", eip2537::g2_msm);
+test_eip2537!(
+    test_eip2537_pairing_check,
+    "pairing_check_bls",
+    eip2537::pairing_check
+);
+test_eip2537!(
+    test_eip2537_map_fp_to_g1,
+    "map_fp_to_G1_bls",
+    eip2537::map_fp_to_g1
+);
+test_eip2537!(
+    test_eip2537_map_fp2_to_g2,
+    "map_fp2_to_G2_bls",
+    eip2537::map_fp2_to_g2
+);
+
+#[test]
+fn test_eip2537_encoding_roundtrip() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip2537::decode_g1(&eip2537::encode_g1(&p)), Ok(p));
+        let q = G2Projective::rand(&mut rng).into_affine();
+        assert_eq!(eip2537::decode_g2(&eip2537::encode_g2(&q)), Ok(q));
+    }
+
+    let g1_zero = G1Affine::zero();
+    assert_eq!(
+        eip2537::encode_g1(&g1_zero),
+        [0u8; eip2537::G1_ENCODED_SIZE]
+    );
+    assert_eq!(
+        eip2537::decode_g1(&[0u8; eip2537::G1_ENCODED_SIZE]),
+        Ok(g1_zero)
+    );
+    let g2_zero = G2Affine::zero();
+    assert_eq!(
+        eip2537::encode_g2(&g2_zero),
+        [0u8; eip2537::G2_ENCODED_SIZE]
+    );
+    assert_eq!(
+        eip2537::decode_g2(&[0u8; eip2537::G2_ENCODED_SIZE]),
+        Ok(g2_zero)
+    );
+}
+
+fn read_hex(value: &serde_json::Value) -> Vec<u8> {
+    hex::decode(value.as_str().unwrap().trim_start_matches("0x")).unwrap()
+}
+
+fn read_bls_vectors(vectors: &str) -> Vec<serde_json::Value> {
+    serde_json::from_str(vectors).unwrap()
+}
+
+/// Decodes the public keys and signature of an Ethereum verification case,
+/// returning `None` if any of them is invalid.
+fn read_bls_verify_case(case: &serde_json::Value) -> Option<(Vec<G1Affine>, G2Affine)> {
+    let input = &case["input"];
+    let public = match input.get("pubkeys") {
+        Some(keys) => keys.as_array().unwrap().iter().collect(),
+        None => vec![&input["pubkey"]],
+    };
+    let public = public
+        .into_iter()
+        .map(|key| G2_POP.decode_public_key(&read_hex(key)).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let signature = G2_POP
+        .decode_signature(&read_hex(&input["signature"]))
+        .ok()?;
+    Some((public, signature))
+}
+
+#[test]
+fn test_bls_eth_sign() {
+    for case in read_bls_vectors(include_str!("bls/eth/sign.json")) {
+        let secret = Fr::from_be_bytes_mod_order(&read_hex(&case["input"]["privkey"]));
+        let message = read_hex(&case["input"]["message"]);
+        let signature = G2_POP.sign(&secret, &message);
+        if case["output"].is_null() {
+            assert_eq!(
+                signature,
+                Err(signature::Error::InvalidSecretKey),
+                "{}",
+                case["name"]
+            );
+        } else {
+            let signature = signature.unwrap();
+            assert_eq!(
+                encode_point(&signature),
+                read_hex(&case["output"]),
+                "{}",
+                case["name"]
+            );
+            let public = G2_POP.sk_to_pk(&secret).unwrap();
+            assert_eq!(G2_POP.verify(&public, &message, &signature), Ok(()));
+        }
+    }
+}
+
+#[test]
+fn test_bls_eth_verify() {
+    for case in read_bls_vectors(include_str!("bls/eth/verify.json")) {
+        let message = read_hex(&case["input"]["message"]);
+        let valid = read_bls_verify_case(&case).is_some_and(|(public, signature)| {
+            G2_POP.verify(&public[0], &message, &signature).is_ok()
+        });
+        assert_eq!(valid, case["output"].as_bool().unwrap(), "{}", case["name"]);
+    }
+}
+
+#[test]
+fn test_bls_eth_aggregate() {
+    for case in read_bls_vectors(include_str!("bls/eth/aggregate.json")) {
+        let signatures: Vec<G2Affine> = case["input"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| G2_POP.decode_signature(&read_hex(s)).unwrap())
+            .collect();
+        let aggregate = G2_POP.aggregate(&signatures);
+        if case["output"].is_null() {
+            assert_eq!(
+                aggregate,
+                Err(signature::Error::EmptyInput),
+                "{}",
+                case["name"]
+            );
+        } else {
+            assert_eq!(
+                encode_point(&aggregate.unwrap()),
+                read_hex(&case["output"]),
+                "{}",
+                case["name"]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bls_eth_fast_aggregate_verify() {
+    for case in read_bls_vectors(include_str!("bls/eth/fast_aggregate_verify.json")) {
+        let message = read_hex(&case["input"]["message"]);
+        let valid = read_bls_verify_case(&case).is_some_and(|(public, signature)| {
+            G2_POP
+                .fast_aggregate_verify(&public, &message, &signature)
+                .is_ok()
+        });
+        assert_eq!(valid, case["output"].as_bool().unwrap(), "{}", case["name"]);
+    }
+}
+
+#[test]
+fn test_bls_eth_aggregate_verify() {
+    for case in read_bls_vectors(include_str!("bls/eth/aggregate_verify.json")) {
+        let messages: Vec<Vec<u8>> = case["input"]["messages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(read_hex)
+            .collect();
+        let valid = read_bls_verify_case(&case).is_some_and(|(public, signature)| {
+            let items: Vec<(G1Affine, &[u8])> = public
+                .into_iter()
+                .zip(messages.iter().map(|m| &m[..]))
+                .collect();
+            G2_POP.aggregate_verify(&items, &signature).is_ok()
+        });
+        assert_eq!(valid, case["output"].as_bool().unwrap(), "{}", case["name"]);
+    }
+}
+
+fn test_bls_ciphersuite<V: signature::Variant>(
+    ciphersuite: &Ciphersuite<V>,
+    case: &serde_json::Value,
+) {
+    let id = case["ciphersuite"].as_str().unwrap();
+    assert_eq!(ciphersuite.id(), id.as_bytes());
+
+    let mut public = Vec::new();
+    let mut messages = Vec::new();
+    let mut signatures = Vec::new();
+    for item in case["sign"].as_array().unwrap() {
+        let secret = Fr::from_be_bytes_mod_order(&read_hex(&item["privkey"]));
+        let key = ciphersuite.sk_to_pk(&secret).unwrap();
+        assert_eq!(encode_point(&key), read_hex(&item["pubkey"]), "{}", id);
+        assert_eq!(
+            ciphersuite.decode_public_key(&read_hex(&item["pubkey"])),
+            Ok(key)
+        );
+
+        let message = read_hex(&item["message"]);
+        let signature = ciphersuite.sign(&secret, &message).unwrap();
+        assert_eq!(
+            encode_point(&signature),
+            read_hex(&item["signature"]),
+            "{}",
+            id
+        );
+        assert_eq!(ciphersuite.verify(&key, &message, &signature), Ok(()));
+        assert_eq!(
+            ciphersuite.verify(&key, &[0x42], &signature),
+            Err(signature::Error::InvalidSignature)
+        );
+        public.push(key);
+        messages.push(message);
+        signatures.push(signature);
+    }
+
+    let aggregate = ciphersuite.aggregate(&signatures).unwrap();
+    assert_eq!(
+        encode_point(&aggregate),
+        read_hex(&case["aggregate_signature"]),
+        "{}",
+        id
+    );
+    let items: Vec<(signature::PublicKey<V>, &[u8])> = public
+        .iter()
+        .copied()
+        .zip(messages.iter().map(|m| &m[..]))
+        .collect();
+    assert_eq!(ciphersuite.aggregate_verify(&items, &aggregate), Ok(()));
+
+    // Two signatures on the same message.
+    let repeated = &case["repeated_message"];
+    let keys: Vec<signature::PublicKey<V>> = repeated["pubkeys"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|key| ciphersuite.decode_public_key(&read_hex(key)).unwrap())
+        .collect();
+    let message = read_hex(&repeated["message"]);
+    let signature = ciphersuite
+        .decode_signature(&read_hex(&repeated["signature"]))
+        .unwrap();
+    let items: Vec<(signature::PublicKey<V>, &[u8])> =
+        keys.iter().map(|key| (*key, &message[..])).collect();
+    let result = ciphersuite.aggregate_verify(&items, &signature);
+    if repeated["output"].as_bool().unwrap() {
+        assert_eq!(result, Ok(()), "{}", id);
+    } else {
+        assert_eq!(result, Err(signature::Error::DuplicateMessage), "{}", id);
+    }
+
+    if ciphersuite.scheme() == Scheme::ProofOfPossession {
+        assert_eq!(
+            ciphersuite.pop_id(),
+            case["proof_of_possession_dst"].as_str().unwrap().as_bytes()
+        );
+        for ((item, key), proof) in case["sign"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .zip(&public)
+            .zip(case["proofs"].as_array().unwrap())
+        {
+            let secret = Fr::from_be_bytes_mod_order(&read_hex(&item["privkey"]));
+            let expected = ciphersuite.pop_prove(&secret).unwrap();
+            assert_eq!(encode_point(&expected), read_hex(proof), "{}", id);
+            assert_eq!(ciphersuite.pop_verify(key, &expected), Ok(()));
+            // A signature on the encoded key is not a proof of possession.
+            let signature = ciphersuite.sign(&secret, &encode_point(key)).unwrap();
+            assert_eq!(
+                ciphersuite.pop_verify(key, &signature),
+                Err(signature::Error::InvalidSignature)
+            );
+        }
+        let fast = ciphersuite
+            .decode_signature(&read_hex(&case["fast_aggregate_signature"]))
+            .unwrap();
+        assert_eq!(
+            ciphersuite.fast_aggregate_verify(&keys, &message, &fast),
+            Ok(())
+        );
+    } else {
+        assert_eq!(
+            ciphersuite.fast_aggregate_verify(&keys, &message, &signature),
+            Err(signature::Error::UnsupportedScheme)
+        );
+        assert_eq!(
+            ciphersuite.pop_prove(&Fr::one()),
+            Err(signature::Error::UnsupportedScheme)
+        );
+    }
+}
+
+/// The vectors of `bls/ciphersuites.json` were generated with this
+/// implementation, in the layout of the Ethereum consensus tests, and are
+/// regression tests only. `G2_POP` is checked against the Ethereum vectors by
+/// the `test_bls_eth_*` tests, and `G2_BASIC` and `G1_BASIC` against the
+/// signatures of other implementations by
+/// `test_bls_basic_reference_signatures`. No third-party vectors were found
+/// for the other ciphersuites.
+#[test]
+fn test_bls_ciphersuites() {
+    let cases = read_bls_vectors(include_str!("bls/ciphersuites.json"));
+    assert_eq!(cases.len(), 6);
+    test_bls_ciphersuite(&signature::G2_BASIC, &cases[0]);
+    test_bls_ciphersuite(&signature::G2_AUG, &cases[1]);
+    test_bls_ciphersuite(&G2_POP, &cases[2]);
+    test_bls_ciphersuite(&signature::G1_BASIC, &cases[3]);
+    test_bls_ciphersuite(&signature::G1_AUG, &cases[4]);
+    test_bls_ciphersuite(&signature::G1_POP, &cases[5]);
+}
+
+/// Checks that `secret` signs `message` as `signature` under the basic
+/// ciphersuite, with the public key `public`.
+fn check_bls_basic_signature<V: signature::Variant>(
+    ciphersuite: &Ciphersuite<V>,
+    secret: &str,
+    public: &str,
+    message: &[u8],
+    signature: &str,
+) {
+    let secret = Fr::from_be_bytes_mod_order(&hex::decode(secret).unwrap());
+    let key = ciphersuite.sk_to_pk(&secret).unwrap();
+    assert_eq!(encode_point(&key), hex::decode(public).unwrap());
+    let sig = ciphersuite.sign(&secret, message).unwrap();
+    assert_eq!(encode_point(&sig), hex::decode(signature).unwrap());
+    assert!(ciphersuite.verify(&key, message, &sig).is_ok());
+}
+
+/// Checks that `signature` verifies on `message` with `public` under the
+/// basic ciphersuite exactly when `valid` is set.
+fn check_bls_basic_verify<V: signature::Variant>(
+    ciphersuite: &Ciphersuite<V>,
+    valid: bool,
+    public: &str,
+    message: &[u8],
+    signature: &str,
+) {
+    let public = ciphersuite
+        .decode_public_key(&hex::decode(public).unwrap())
+        .unwrap();
+    let signature = ciphersuite
+        .decode_signature(&hex::decode(signature).unwrap())
+        .unwrap();
+    assert_eq!(
+        ciphersuite.verify(&public, message, &signature).is_ok(),
+        valid
+    );
+}
+
+/// Signatures of the basic ciphersuites produced by other implementations:
+/// the regression vectors of the `min_pk` and `min_sig` modules of
+/// fastcrypto, a drand beacon, and the Internet Computer signatures of the
+/// `ic-verify-bls-signature` crate, which use the `G1_BASIC` ciphersuite.
+#[test]
+fn test_bls_basic_reference_signatures() {
+    let secret = "266f9708fd8d3b462b10cdbf5498076c021eb3acfdd47cb1fef647967fe194fb";
+    check_bls_basic_signature(
+        &signature::G2_BASIC,
+        secret,
+        "b157f238403a5b980546fd19ca48f79a2613e3e3a91d14ee69908b8816e4c53665370b2fbd0db62cc4aa0e8caeedc9b5",
+        b"hello, narwhal",
+        "8dec0b9a1a629cc96c57144ee8e7dd5c93acb465286f1214df3b8482c3f16e10db4277ead785f5d5bc77b4e51affd258\
+         0dead4d0d21cf20fc5e2b4bec2586c2bd6c73fee76c11f214871f77dada4c578034c3b978f1cccb82bdd78fe5ee67de1",
+    );
+    check_bls_basic_signature(
+        &signature::G1_BASIC,
+        secret,
+        "8c66dc2c1ea9e53f0985c17b4e7af19912b6d3c40e0c5920a5a12509b4eb3619f5e07ec56ea77f0b30629ba1cc72d75b\
+         139460782a5f0e2f89fb4c42b4b8a5fae3d260102220e63d0754e7e1846deefd3988eade4ed37f1385437d19de1a1618",
+        b"hello, narwhal",
+        "89dff2dc1e9428b9437d50b37f8160eca790110ea2a79b6c88a43a16953466f8e391ff65842b067a1c9441c7c2cebce0",
+    );
+
+    // Round 2373935 of the drand mainnet beacon, whose message is the hash of
+    // the previous signature and the round number.
+    let previous = hex::decode(
+        "a96aace596906562dc525dba4dff734642d71b334d51324f9c9bcb5a3d6caf14b05cde91d6507bf4615cb4285e5b4efd\
+         1358ebc46b80b51e338f9dc46cca17cf2e046765ba857c04101a560887fa81aef101a5bb3b2350884558bd3adc72be37",
+    )
+    .unwrap();
+    let message = Sha256::new()
+        .chain_update(previous)
+        .chain_update(2373935u64.to_be_bytes())
+        .finalize();
+    check_bls_basic_verify(
+        &signature::G2_BASIC,
+        true,
+        "868f005eb8e6e4ca0a47c8a77ceaa5309a47978a7c71bc5cce96366b5d7a569937c529eeda66c7293784a9402801af31",
+        &message,
+        "a2cd8577944b84484ef557a7f92f0d5092779497cc470b1b97680b8f7c807d97250d310b801c7c2185c7c8a21032d454\
+         03b97530ca87bd8f05d0cf4ffceb4bcb9bf7184fb604967db7e9e6ea555bc51b25a9e41fbd51181f712aa73aaec749fe",
+    );
+
+    let secret = Fr::from_be_bytes_mod_order(
+        &hex::decode("6f3977f6051e184b2c412daa1b5c0115ef7ab347cac8d808ffa2c26bd0658243").unwrap(),
+    );
+    let message = hex::decode(
+        "50484522ad8aede64ec7f86b9273b7ed3940481acf93cdd40a2b77f2be2734a14012b2492b6363b12adaeaf0\
+         55c573e4611b085d2e0fe2153d72453a95eaebf350ac3ba6a26ba0bc79f4c0bf5664dfdf5865f69f7fc6b58b\
+         a7d068e8",
+    )
+    .unwrap();
+    assert_eq!(
+        encode_point(&signature::G1_BASIC.sign(&secret, &message).unwrap()),
+        hex::decode(
+            "8f7ad830632657f7b3eae17fd4c3d9ff5c13365eea8d33fd0a1a6d8fbebc5152e066bb0ad61ab64e8a8541c8e3f96de9"
+        )
+        .unwrap()
+    );
+    check_bls_basic_verify(
+        &signature::G1_BASIC,
+        true,
+        "87033f48fd8f327ff5d164e85af31433c6a8c73fc5a65bad5d472127205c73c5168a45e862f5af6d0da5676df45d0a5f\
+         1293a530d5498f812a34a280f6bef869e4ca9b7c275554456d8770733d72ac4006777382fa541873fe002adb12184268",
+        &hex::decode(
+            "e751fdb69185002b13c8d2954c7d0c39546402ecdde9c2a9a2c624293535a5ca2f560a582f705580448fbe1c\
+             cdc0e86af3ba4c487a7f73bc9c312556",
+        )
+        .unwrap(),
+        "98733cc2b312d5787cd4dba6ea0e19a1f1850b9e8c6d5112f12e12db8e7413a4ecb4096c23730566c67d9b2694e4e179",
+    );
+
+    // Two signatures on state roots, which do not verify once their messages
+    // are swapped.
+    let keys = [
+        "814c0e6ec71fab583b08bd81373c255c3c371b2e84863c98a4f1e08b74235d14fb5d9c0cd546d9685f913a0c0b2cc534\
+         1583bf4b4392e467db96d65b9bb4cb717112f8472e0d5a4d14505ffd7484b01291091c5f87b98883463f98091a0baaae",
+        "9933e1f89e8a3c4d7fdcccdbd518089e2bd4d8180a261f18d9c247a52768ebce98dc7328a39814a8f911086a1dd50cbe\
+         015e2a53b7bf78b55288893daa15c346640e8831d72a12bdedd979d28470c34823b8d1c3f4795d9c3984a247132e94fe",
+    ];
+    let messages = [
+        "0d69632d73746174652d726f6f74e6c01e909b4923345ce5970962bcfe3004bfd8474a21dae28f50692502f46d90",
+        "0d69632d73746174652d726f6f74b294b418b11ebe5dd7dd1dcb099e4e0372b9a42aef7a7a37fb4f25667d705ea9",
+    ];
+    let signatures = [
+        "ace9fcdd9bc977e05d6328f889dc4e7c99114c737a494653cb27a1f55c06f4555e0f160980af5ead098acc195010b2f7",
+        "89a2be21b5fa8ac9fab1527e041327ce899d7da971436a1f2165393947b4d942365bfe5488710e61a619ba48388a21b1",
+    ];
+    for (i, key) in keys.iter().enumerate() {
+        for (j, (message, sig)) in messages.iter().zip(&signatures).enumerate() {
+            check_bls_basic_verify(
+                &signature::G1_BASIC,
+                i == j,
+                key,
+                &hex::decode(message).unwrap(),
+                sig,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bls_invalid_inputs() {
+    let mut rng = test_rng();
+    let secret = Fr::rand(&mut rng);
+    let public = G2_POP.sk_to_pk(&secret).unwrap();
+    let signature = G2_POP.sign(&secret, b"message").unwrap();
+
+    assert_eq!(
+        G2_POP.sk_to_pk(&Fr::zero()),
+        Err(signature::Error::InvalidSecretKey)
+    );
+    assert_eq!(
+        G2_POP.key_validate(&G1Affine::zero()),
+        Err(signature::Error::InvalidPublicKey)
+    );
+    assert_eq!(
+        G2_POP.decode_public_key(&encode_point(&G1Affine::zero())),
+        Err(signature::Error::InvalidPublicKey)
+    );
+    assert_eq!(G2_POP.aggregate(&[]), Err(signature::Error::EmptyInput));
+    assert_eq!(
+        G2_POP.aggregate_verify(&[], &signature),
+        Err(signature::Error::EmptyInput)
+    );
+    assert_eq!(
+        G2_POP.fast_aggregate_verify(&[], b"message", &signature),
+        Err(signature::Error::EmptyInput)
+    );
+
+    // Trailing bytes are rejected.
+    let mut bytes = encode_point(&public);
+    bytes.push(0);
+    assert_eq!(
+        G2_POP.decode_public_key(&bytes),
+        Err(signature::Error::InvalidPublicKey)
+    );
+
+    // A point on the curve outside the prime-order subgroup.
+    let outside = loop {
+        let x = Fq2::rand(&mut rng);
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, false) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                break p;
+            }
+        }
+    };
+    assert_eq!(
+        G2_POP.decode_signature(&encode_point(&outside)),
+        Err(signature::Error::InvalidSignature)
+    );
+    assert_eq!(
+        G2_POP.verify(&public, b"message", &outside),
+        Err(signature::Error::InvalidSignature)
+    );
+
+    // Keys that cancel out aggregate to the point at infinity.
+    let negated = -public;
+    assert_eq!(
+        G2_POP.fast_aggregate_verify(&[public, negated], b"message", &G2Affine::zero()),
+        Err(signature::Error::InvalidPublicKey)
+    );
+}
@@ -0,0 +1,192 @@
+//! Encoding of BN254 points as used by Ethereum, together with the
+//! `alt_bn128` precompiles of [EIP-196] and [EIP-197].
+//!
+//! Field elements are encoded as 32-byte big-endian integers, which must be
+//! smaller than the modulus. A `G1` point is encoded as `x || y` and a `G2`
+//! point as `x.c1 || x.c0 || y.c1 || y.c0`, i.e. with the imaginary part of each
+//! coordinate first. The point at infinity is encoded as all zeros.
+//!
+//! [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+//! [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInt, One, PrimeField, Zero};
+use ark_std::{fmt, vec::Vec};
+
+use crate::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+
+/// Size in bytes of an encoded base field element.
+pub const FQ_ENCODED_SIZE: usize = 32;
+/// Size in bytes of an encoded `G1` point.
+pub const G1_ENCODED_SIZE: usize = 2 * FQ_ENCODED_SIZE;
+/// Size in bytes of an encoded `G2` point.
+pub const G2_ENCODED_SIZE: usize = 4 * FQ_ENCODED_SIZE;
+/// Size in bytes of an encoded scalar.
+pub const SCALAR_ENCODED_SIZE: usize = 32;
+
+/// Size in bytes of the input of the `ecAdd` precompile.
+pub const EC_ADD_INPUT_SIZE: usize = 2 * G1_ENCODED_SIZE;
+/// Size in bytes of the input of the `ecMul` precompile.
+pub const EC_MUL_INPUT_SIZE: usize = G1_ENCODED_SIZE + SCALAR_ENCODED_SIZE;
+/// Size in bytes of a single (`G1`, `G2`) pair in the input of the
+/// `ecPairing` precompile.
+pub const EC_PAIRING_PAIR_SIZE: usize = G1_ENCODED_SIZE + G2_ENCODED_SIZE;
+
+/// Errors returned when decoding points or running the precompiles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input of `ecPairing` is not a multiple of [`EC_PAIRING_PAIR_SIZE`].
+    InvalidInputLength,
+    /// A coordinate is not smaller than the base field modulus.
+    CoordinateExceedsModulus,
+    /// The decoded point does not satisfy the curve equation.
+    NotOnCurve,
+    /// The decoded `G2` point is not in the prime-order subgroup.
+    NotInSubgroup,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInputLength => write!(f, "invalid input length"),
+            Error::CoordinateExceedsModulus => write!(f, "coordinate exceeds modulus"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::NotInSubgroup => write!(f, "point is not in the prime-order subgroup"),
+        }
+    }
+}
+
+fn decode_u256(bytes: &[u8]) -> BigInt<4> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    BigInt::new(limbs)
+}
+
+fn encode_fq(f: Fq, bytes: &mut [u8]) {
+    let limbs = f.into_bigint().0;
+    for (limb, chunk) in limbs.iter().rev().zip(bytes.chunks_exact_mut(8)) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+}
+
+fn decode_fq(bytes: &[u8]) -> Result<Fq, Error> {
+    Fq::from_bigint(decode_u256(bytes)).ok_or(Error::CoordinateExceedsModulus)
+}
+
+/// Encodes a `G1` point as `x || y`, with infinity encoded as `(0, 0)`.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_ENCODED_SIZE] {
+    let mut bytes = [0u8; G1_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        encode_fq(x, &mut bytes[..32]);
+        encode_fq(y, &mut bytes[32..]);
+    }
+    bytes
+}
+
+/// Decodes a `G1` point encoded by [`encode_g1`], checking that it is on the
+/// curve. Since `G1` has prime order, no subgroup check is needed.
+pub fn decode_g1(bytes: &[u8; G1_ENCODED_SIZE]) -> Result<G1Affine, Error> {
+    let x = decode_fq(&bytes[..32])?;
+    let y = decode_fq(&bytes[32..])?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    let p = G1Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    Ok(p)
+}
+
+/// Encodes a `G2` point as `x.c1 || x.c0 || y.c1 || y.c0`, with infinity
+/// encoded as all zeros.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_ENCODED_SIZE] {
+    let mut bytes = [0u8; G2_ENCODED_SIZE];
+    if let Some((x, y)) = p.xy() {
+        encode_fq(x.c1, &mut bytes[..32]);
+        encode_fq(x.c0, &mut bytes[32..64]);
+        encode_fq(y.c1, &mut bytes[64..96]);
+        encode_fq(y.c0, &mut bytes[96..]);
+    }
+    bytes
+}
+
+/// Decodes a `G2` point encoded by [`encode_g2`], checking that it is on the
+/// curve and in the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8; G2_ENCODED_SIZE]) -> Result<G2Affine, Error> {
+    let x = Fq2::new(decode_fq(&bytes[32..64])?, decode_fq(&bytes[..32])?);
+    let y = Fq2::new(decode_fq(&bytes[96..])?, decode_fq(&bytes[64..96])?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    let p = G2Affine::new_unchecked(x, y);
+    if !p.is_on_curve() {
+        return Err(Error::NotOnCurve);
+    }
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(p)
+}
+
+/// Copies `input` into a zeroed buffer of size `N`, truncating longer inputs
+/// and padding shorter ones with zeros, as the EVM does for call data.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut buf = [0u8; N];
+    let len = input.len().min(N);
+    buf[..len].copy_from_slice(&input[..len]);
+    buf
+}
+
+/// The `ecAdd` precompile (address `0x06`) of [EIP-196].
+///
+/// The input is padded with zeros to [`EC_ADD_INPUT_SIZE`] bytes, and any
+/// bytes beyond that are ignored.
+///
+/// [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+pub fn ec_add(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let input = padded::<EC_ADD_INPUT_SIZE>(input);
+    let a = decode_g1(input[..G1_ENCODED_SIZE].try_into().unwrap())?;
+    let b = decode_g1(input[G1_ENCODED_SIZE..].try_into().unwrap())?;
+    Ok(encode_g1(&(a + b).into_affine()))
+}
+
+/// The `ecMul` precompile (address `0x07`) of [EIP-196].
+///
+/// The input is padded with zeros to [`EC_MUL_INPUT_SIZE`] bytes, and any
+/// bytes beyond that are ignored. The scalar is an arbitrary 256-bit
+/// big-endian integer and is not required to be reduced.
+///
+/// [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+pub fn ec_mul(input: &[u8]) -> Result<[u8; G1_ENCODED_SIZE], Error> {
+    let input = padded::<EC_MUL_INPUT_SIZE>(input);
+    let p = decode_g1(input[..G1_ENCODED_SIZE].try_into().unwrap())?;
+    let scalar = decode_u256(&input[G1_ENCODED_SIZE..]);
+    Ok(encode_g1(&p.mul_bigint(scalar).into_affine()))
+}
+
+/// The `ecPairing` precompile (address `0x08`) of [EIP-197].
+///
+/// The input is a sequence of encoded (`G1`, `G2`) pairs, and the output is
+/// the 32-byte big-endian encoding of `1` if the product of their pairings
+/// is the identity, and of `0` otherwise. An empty input yields `1`.
+///
+/// [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], Error> {
+    if input.len() % EC_PAIRING_PAIR_SIZE != 0 {
+        return Err(Error::InvalidInputLength);
+    }
+    let mut g1 = Vec::with_capacity(input.len() / EC_PAIRING_PAIR_SIZE);
+    let mut g2 = Vec::with_capacity(input.len() / EC_PAIRING_PAIR_SIZE);
+    for pair in input.chunks_exact(EC_PAIRING_PAIR_SIZE) {
+        g1.push(decode_g1(pair[..G1_ENCODED_SIZE].try_into().unwrap())?);
+        g2.push(decode_g2(pair[G1_ENCODED_SIZE..].try_into().unwrap())?);
+    }
+    let mut output = [0u8; 32];
+    if Bn254::multi_pairing(g1, g2).0.is_one() {
+        output[31] = 1;
+    }
+    Ok(output)
+}
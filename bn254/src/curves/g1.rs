@@ -1,18 +1,30 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
     bn,
+    hashing::map_to_curve_hasher::MapToCurveBasedHasher,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
-use crate::{Fq, Fr};
+use sha2::Sha256;
+
+use crate::{
+    svdw::{SVDWConfig, SVDWMap},
+    Fq, Fr,
+};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Config;
 
 pub type G1Affine = Affine<Config>;
 
+/// Hashes to G1 with the `BN254G1_XMD:SHA-256_SVDW_RO_` suite, with the DST
+/// passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<Projective<Config>, XmdFieldHasher<Sha256>, SVDWMap<Config>>;
+
 impl CurveConfig for Config {
     type BaseField = Fq;
     type ScalarField = Fr;
@@ -76,6 +88,27 @@ impl GLVConfig for Config {
     }
 }
 
+// Parameters of the BN254G1_XMD:SHA-256_SVDW_RO_ suite, computed as in
+// [RFC 9380, section 6.6.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-shallue-van-de-woestijne-met).
+impl SVDWConfig for Config {
+    /// Z = 1
+    const Z: Fq = Fq::ONE;
+
+    /// C1 = g(Z) = 4
+    const C1: Fq = MontFp!("4");
+
+    /// C2 = -Z / 2
+    const C2: Fq =
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291");
+
+    /// C3 = sqrt(-g(Z) * 3 * Z^2)
+    const C3: Fq = MontFp!("8815841940592487685674414971303048083897117035520822607866");
+
+    /// C4 = -4 * g(Z) / (3 * Z^2)
+    const C4: Fq =
+        MontFp!("7296080957279758407415468581752425029565437052432607887563012631548408736189");
+}
+
 /// G1_GENERATOR_X = 1
 pub const G1_GENERATOR_X: Fq = Fq::ONE;
 
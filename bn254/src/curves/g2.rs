@@ -1,14 +1,28 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    bn::BnConfig,
+    hashing::map_to_curve_hasher::MapToCurveBasedHasher,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup, PrimeGroup,
 };
-use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
-use crate::{Fq, Fq2, Fr};
+use sha2::Sha256;
+
+use crate::{
+    svdw::{SVDWConfig, SVDWMap},
+    Fq, Fq2, Fr,
+};
 
 pub type G2Affine = Affine<Config>;
 
+/// Hashes to G2 with the `BN254G2_XMD:SHA-256_SVDW_RO_` suite, with the DST
+/// passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<Projective<Config>, XmdFieldHasher<Sha256>, SVDWMap<Config>>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Config;
 
@@ -49,6 +63,34 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        // Algorithm from Section 3.1 of https://eprint.iacr.org/2022/352.
+        //
+        // Checks that psi(P) = [6X^2]P
+        let p = point.into_group();
+        let six_x_squared_p = p.mul_bigint(SIX_X_SQUARED);
+
+        psi(&p) == six_x_squared_p
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // Based on Section 6.1 of [\[FKR11\]]:
+        // h(P) = [x]P + psi([3x]P) + psi^2([x]P) + psi^3(P)
+        // is a multiple of the cofactor, and is the effective cofactor used by
+        // the BN254G2 hash-to-curve suites.
+        //
+        // [\[FKR11\]] <http://cacr.uwaterloo.ca/techreports/2011/cacr2011-26.pdf>
+        let p = p.into_group();
+        let x_p = p.mul_bigint(crate::Config::X);
+        let psi_3x_p = psi(&(x_p.double() + x_p));
+        let psi2_x_p = psi(&psi(&x_p));
+        let psi3_p = psi(&psi(&psi(&p)));
+
+        (x_p + psi_3x_p + psi2_x_p + psi3_p).into_affine()
+    }
 }
 
 impl GLVConfig for Config {
@@ -80,6 +122,52 @@ impl GLVConfig for Config {
     }
 }
 
+// Parameters of the BN254G2_XMD:SHA-256_SVDW_RO_ suite, computed as in
+// [RFC 9380, section 6.6.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-shallue-van-de-woestijne-met).
+impl SVDWConfig for Config {
+    /// Z = 1
+    const Z: Fq2 = Fq2::ONE;
+
+    /// C1 = g(Z)
+    const C1: Fq2 = Fq2::new(
+        MontFp!("19485874751759354771024239261021720505790618469301721065564631296452457478374"),
+        MontFp!("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+    );
+
+    /// C2 = -Z / 2
+    const C2: Fq2 = Fq2::new(
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291"),
+        Fq::ZERO,
+    );
+
+    /// C3 = sqrt(-g(Z) * 3 * Z^2)
+    const C3: Fq2 = Fq2::new(
+        MontFp!("18992192239972082890849143911285057164064277369389217330423471574879236301292"),
+        MontFp!("21819008332247140148575583693947636719449476128975323941588917397607662637108"),
+    );
+
+    /// C4 = -4 * g(Z) / (3 * Z^2)
+    const C4: Fq2 = Fq2::new(
+        MontFp!("10499238450719652342378357227399831140106360636427411350395554762472100376473"),
+        MontFp!("6940174569119770192419592065569379906172001098655407502803841283667998553941"),
+    );
+}
+
+/// SIX_X_SQUARED = 6 * X^2 = 147946756881789318990833708069417712966
+pub(crate) const SIX_X_SQUARED: &[u64] = &[0xf83e9682e87cfd46, 0x6f4d8248eeb859fb];
+
+/// psi(x, y) = (x^p * TWIST_MUL_BY_Q_X, y^p * TWIST_MUL_BY_Q_Y) is the
+/// untwist-Frobenius-twist endomorphism of G2.
+pub fn psi(p: &Projective<Config>) -> Projective<Config> {
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.y.frobenius_map_in_place(1);
+    res.z.frobenius_map_in_place(1);
+    res.x *= crate::Config::TWIST_MUL_BY_Q_X;
+    res.y *= crate::Config::TWIST_MUL_BY_Q_Y;
+    res
+}
+
 pub const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = Fq2::new(G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
@@ -1,13 +1,18 @@
+use ark_curve_utils::pairing;
 use ark_ec::{
     bn,
     bn::{Bn, BnConfig, TwistType},
+    pairing::PairingOutput,
 };
 use ark_ff::MontFp;
+use ark_serialize::{Compress, Read, SerializationError};
 
 use crate::*;
 
+pub mod eth;
 pub mod g1;
 pub mod g2;
+pub mod svdw;
 
 #[cfg(test)]
 mod tests;
@@ -47,3 +52,20 @@ pub type G1Affine = bn::G1Affine<Config>;
 pub type G1Projective = bn::G1Projective<Config>;
 pub type G2Affine = bn::G2Affine<Config>;
 pub type G2Projective = bn::G2Projective<Config>;
+
+/// Checks whether `f` lies in the order-`r` subgroup of `Fq12^*` that contains
+/// the pairing outputs with `ark_curve_utils::pairing::is_in_gt`, as
+/// `q = 6X^2 (mod r)`.
+pub fn is_in_gt(f: &Fq12) -> bool {
+    pairing::is_in_gt(f, g2::SIX_X_SQUARED, false)
+}
+
+/// Deserializes a pairing output, checking that it lies in GT with
+/// [`is_in_gt`] rather than with the exponentiation by `r` of
+/// `Validate::Yes`.
+pub fn deserialize_pairing_output<R: Read>(
+    reader: R,
+    compress: Compress,
+) -> Result<PairingOutput<Bn254>, SerializationError> {
+    pairing::deserialize_pairing_output(reader, compress, g2::SIX_X_SQUARED, false)
+}
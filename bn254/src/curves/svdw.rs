@@ -0,0 +1,145 @@
+//! The Shallue--van de Woestijne map to the curve. It is public so that it
+//! can be combined with other field hashers than the ones of
+//! [`crate::g1::Hasher`] and [`crate::g2::Hasher`].
+
+use ark_ec::{
+    hashing::{curve_maps::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    models::short_weierstrass::{Affine, Projective, SWCurveConfig},
+};
+use ark_ff::{AdditiveGroup, Field, One, Zero};
+use ark_std::marker::PhantomData;
+
+/// Trait defining the necessary parameters for the Shallue--van de Woestijne
+/// hash-to-curve method for the curves of Weierstrass form of:
+/// y^2 = x^3 + a*x + b, where `a` may be zero. From [\[SW06\]], following
+/// the constant-time description of [\[HSSWW23\], Section 6.6.1].
+///
+/// The constants are those of [\[HSSWW23\]], where `g(x) = x^3 + a*x + b`.
+///
+/// - [\[SW06\]] <https://doi.org/10.1007/11792086_36>
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub trait SVDWConfig: SWCurveConfig {
+    /// An element of the base field such that g(Z) != 0,
+    /// -(3 * Z^2 + 4 * a) / (4 * g(Z)) is a non-zero square, and at least one
+    /// of g(Z) and g(-Z / 2) is square. [\[HSSWW23\]] mandates the one found
+    /// by `find_z_svdw` of its Appendix H.1.
+    const Z: Self::BaseField;
+
+    /// C1 = g(Z)
+    const C1: Self::BaseField;
+
+    /// C2 = -Z / 2
+    const C2: Self::BaseField;
+
+    /// C3 = sqrt(-g(Z) * (3 * Z^2 + 4 * a)), with sgn0(C3) = 0
+    const C3: Self::BaseField;
+
+    /// C4 = -4 * g(Z) / (3 * Z^2 + 4 * a)
+    const C4: Self::BaseField;
+}
+
+/// Represents the SVDW hash-to-curve map defined by `P`.
+pub struct SVDWMap<P: SVDWConfig>(PhantomData<fn() -> P>);
+
+/// g(x) = x^3 + a*x + b
+fn curve_rhs<P: SWCurveConfig>(x: P::BaseField) -> P::BaseField {
+    let mut rhs = x.square() * x + P::COEFF_B;
+    if !P::COEFF_A.is_zero() {
+        rhs += P::mul_by_a(x);
+    }
+    rhs
+}
+
+/// Unlike `legendre().is_qr()`, this also holds for zero, as is_square does in
+/// [\[HSSWW23\], Section 4].
+fn is_square<F: Field>(x: F) -> bool {
+    !x.legendre().is_qnr()
+}
+
+impl<P: SVDWConfig> MapToCurve<Projective<P>> for SVDWMap<P> {
+    /// Checks if `P` represents a valid map.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        let one = P::BaseField::one();
+        let two = one.double();
+        let four = two.double();
+        let three_z2_four_a = P::Z.square() * (two + one) + P::COEFF_A * four;
+        let g_z = curve_rhs::<P>(P::Z);
+
+        debug_assert!(!g_z.is_zero(), "g(Z) should be non-zero for the SVDW map");
+        debug_assert!(
+            (-three_z2_four_a / (g_z * four)).legendre().is_qr(),
+            "-(3 * Z^2 + 4 * A) / (4 * g(Z)) should be a non-zero square for the SVDW map"
+        );
+        debug_assert!(
+            is_square(g_z) || is_square(curve_rhs::<P>(-P::Z / two)),
+            "g(Z) or g(-Z / 2) should be a square for the SVDW map"
+        );
+
+        debug_assert_eq!(P::C1, g_z, "C1 is not equal to g(Z)");
+        debug_assert_eq!(P::C2, -P::Z / two, "C2 is not equal to -Z / 2");
+        debug_assert_eq!(
+            P::C3.square(),
+            -g_z * three_z2_four_a,
+            "C3 is not a square root of -g(Z) * (3 * Z^2 + 4 * A)"
+        );
+        debug_assert!(!parity(&P::C3), "sgn0(C3) should be 0");
+        debug_assert_eq!(
+            P::C4,
+            -g_z * four / three_z2_four_a,
+            "C4 is not equal to -4 * g(Z) / (3 * Z^2 + 4 * A)"
+        );
+
+        Ok(())
+    }
+
+    /// Map an arbitrary base field element to a curve point.
+    fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        // 1. tv1 = u^2 * c1
+        // 2. tv2 = 1 + tv1
+        // 3. tv1 = 1 - tv1
+        // 4. tv3 = inv0(tv1 * tv2)
+        // 5. tv4 = u * tv1 * tv3 * c3
+        let one = P::BaseField::one();
+        let mut tv1 = element.square() * P::C1;
+        let tv2 = one + tv1;
+        tv1 = one - tv1;
+        let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = element * tv1 * tv3 * P::C3;
+
+        // 6. x1 = c2 - tv4, e1 = is_square(g(x1))
+        // 7. x2 = c2 + tv4, e2 = is_square(g(x2)) AND NOT e1
+        // 8. x3 = Z + c4 * (tv2^2 * tv3)^2
+        // 9. x = x1 if e1, else x2 if e2, else x3
+        let x1 = P::C2 - tv4;
+        let gx1 = curve_rhs::<P>(x1);
+        let (x, gx) = if is_square(gx1) {
+            (x1, gx1)
+        } else {
+            let x2 = P::C2 + tv4;
+            let gx2 = curve_rhs::<P>(x2);
+            if is_square(gx2) {
+                (x2, gx2)
+            } else {
+                let x3 = P::Z + P::C4 * (tv2.square() * tv3).square();
+                (x3, curve_rhs::<P>(x3))
+            }
+        };
+
+        // 10. y = sqrt(g(x)), with sgn0(y) == sgn0(u)
+        let y = gx
+            .sqrt()
+            .expect("One of g(x1), g(x2), g(x3) is a square by choice of Z. Q.E.D");
+        let y = if parity(&y) != parity(&element) {
+            -y
+        } else {
+            y
+        };
+
+        let point_on_curve = Affine::<P>::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "svdw mapped to a point off the curve"
+        );
+        Ok(point_on_curve)
+    }
+}
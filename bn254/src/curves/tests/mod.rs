@@ -0,0 +1,261 @@
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::{
+    groups::gt_membership_test,
+    hashing::{hash_to_curve_test, read_field},
+};
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::{
+    short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{fields::Field, BigInteger, PrimeField};
+use ark_std::{rand::Rng, test_rng, UniformRand, Zero};
+use sha2::Sha256;
+
+use crate::{
+    deserialize_pairing_output, eth, g1, g2, is_in_gt,
+    svdw::{SVDWConfig, SVDWMap},
+    Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
+
+extern crate std;
+use std::{format, fs::File, io::BufReader, vec::Vec};
+
+test_group!(g1; G1Projective; sw);
+test_group!(g2; G2Projective; sw);
+test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bn254>; msm);
+test_pairing!(pairing; crate::Bn254);
+test_group!(g1_glv; G1Projective; glv);
+test_group!(g2_glv; G2Projective; glv);
+
+fn read_point<P: SVDWConfig>(x: P::BaseField, y: P::BaseField) -> Affine<P> {
+    let p = Affine::<P>::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+fn test_svdw_suite<P: SVDWConfig>(filename: &str) {
+    let file = File::open(filename).unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    assert_eq!(data["map"]["name"], "SVDW");
+    assert_eq!(read_field::<P::BaseField>(&data["Z"]), P::Z);
+
+    hash_to_curve_test::<Projective<P>, XmdFieldHasher<Sha256>, SVDWMap<P>>(
+        filename,
+        read_point::<P>,
+    );
+}
+
+#[test]
+fn test_g1_svdw_hash_to_curve() {
+    test_svdw_suite::<g1::Config>("./src/curves/tests/BN254G1_XMD-SHA-256_SVDW_RO_.json");
+}
+
+#[test]
+fn test_g1_svdw_encode_to_curve() {
+    test_svdw_suite::<g1::Config>("./src/curves/tests/BN254G1_XMD-SHA-256_SVDW_NU_.json");
+}
+
+#[test]
+fn test_g2_svdw_hash_to_curve() {
+    test_svdw_suite::<g2::Config>("./src/curves/tests/BN254G2_XMD-SHA-256_SVDW_RO_.json");
+}
+
+#[test]
+fn test_g2_svdw_encode_to_curve() {
+    test_svdw_suite::<g2::Config>("./src/curves/tests/BN254G2_XMD-SHA-256_SVDW_NU_.json");
+}
+
+#[test]
+fn test_g2_subgroup_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    let generator = G2Projective::rand(&mut rng).into_affine();
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_subgroup_non_membership_via_endomorphism() {
+    let mut rng = test_rng();
+    loop {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            if !p.mul_bigint(Fr::characteristic()).is_zero() {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+                return;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_membership_agrees_with_scalar_mul() {
+    let mut rng = test_rng();
+    let mut non_members = 0;
+    while non_members < 10 {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            // Points with a nonzero component outside the prime-order subgroup,
+            // including those shifted by an element of the subgroup.
+            let q = (p + G2Projective::rand(&mut rng)).into_affine();
+            for p in [p, q] {
+                let in_subgroup = p.mul_bigint(Fr::characteristic()).is_zero();
+                assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+                if !in_subgroup {
+                    non_members += 1;
+                }
+            }
+            assert!(p
+                .clear_cofactor()
+                .is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+    assert!(G2Affine::zero().is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_cofactor_clearing() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let x = Fq2::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, greatest) {
+            assert!(!p.mul_bigint(Fr::characteristic()).is_zero());
+            let p = p.clear_cofactor();
+            assert!(p.is_on_curve());
+            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn test_gt_membership() {
+    gt_membership_test::<Bn254>(is_in_gt, |bytes, compress| {
+        deserialize_pairing_output(bytes, compress)
+    });
+}
+
+/// Checks a precompile against the vectors of go-ethereum in
+/// core/vm/testdata/precompiles, as vendored by solana-bn254 2.2.2, which
+/// keeps a subset of the `bn256Add.json` cases under its own names.
+fn test_eth_precompile<const N: usize>(
+    name: &str,
+    precompile: fn(&[u8]) -> Result<[u8; N], eth::Error>,
+) {
+    let file = File::open(format!("./src/curves/tests/{}.json", name)).unwrap();
+    let cases: Vec<serde_json::Value> = serde_json::from_reader(BufReader::new(file)).unwrap();
+    for case in cases {
+        let input = hex::decode(case["Input"].as_str().unwrap()).unwrap();
+        let expected = hex::decode(case["Expected"].as_str().unwrap()).unwrap();
+        let output = precompile(&input)
+            .unwrap_or_else(|e| panic!("{}: unexpected error: {}", case["Name"], e));
+        assert_eq!(output.as_slice(), &expected[..], "{}", case["Name"]);
+    }
+}
+
+#[test]
+fn test_eth_ec_add() {
+    test_eth_precompile("bn256Add", eth::ec_add);
+}
+
+#[test]
+fn test_eth_ec_mul() {
+    test_eth_precompile("bn256ScalarMul", eth::ec_mul);
+}
+
+#[test]
+fn test_eth_ec_pairing() {
+    test_eth_precompile("bn256Pairing", eth::ec_pairing);
+}
+
+#[test]
+fn test_eth_errors() {
+    let mut rng = test_rng();
+    let g1 = eth::encode_g1(&G1Projective::rand(&mut rng).into_affine());
+    let g2 = eth::encode_g2(&G2Projective::rand(&mut rng).into_affine());
+    let modulus = Fq::MODULUS.to_bytes_be();
+
+    // (0, 1) is neither on the curve nor on its twist.
+    let mut g1_not_on_curve = [0u8; eth::G1_ENCODED_SIZE];
+    g1_not_on_curve[eth::G1_ENCODED_SIZE - 1] = 1;
+    let mut g2_not_on_curve = [0u8; eth::G2_ENCODED_SIZE];
+    g2_not_on_curve[eth::G2_ENCODED_SIZE - 1] = 1;
+    let mut g1_x_is_modulus = g1;
+    g1_x_is_modulus[..eth::FQ_ENCODED_SIZE].copy_from_slice(&modulus);
+    let mut g2_x_is_modulus = g2;
+    g2_x_is_modulus[..eth::FQ_ENCODED_SIZE].copy_from_slice(&modulus);
+    let g2_not_in_subgroup = loop {
+        let x = Fq2::rand(&mut rng);
+        if let Some(q) = G2Affine::get_point_from_x_unchecked(x, rng.gen()) {
+            if !q.is_in_correct_subgroup_assuming_on_curve() {
+                break eth::encode_g2(&q);
+            }
+        }
+    };
+
+    use eth::Error::*;
+    assert_eq!(
+        eth::ec_add(&[g1, g1_not_on_curve].concat()),
+        Err(NotOnCurve)
+    );
+    assert_eq!(eth::ec_add(&g1_x_is_modulus), Err(CoordinateExceedsModulus));
+    // The scalar is zero-padded, but the point is still checked.
+    assert_eq!(eth::ec_mul(&g1_not_on_curve), Err(NotOnCurve));
+    assert_eq!(eth::ec_mul(&g1_x_is_modulus), Err(CoordinateExceedsModulus));
+
+    let pair = [&g1[..], &g2[..]].concat();
+    assert_eq!(eth::ec_pairing(&pair[1..]), Err(InvalidInputLength));
+    for (g1, g2, error) in [
+        (g1_not_on_curve, g2, NotOnCurve),
+        (g1_x_is_modulus, g2, CoordinateExceedsModulus),
+        (g1, g2_not_on_curve, NotOnCurve),
+        (g1, g2_x_is_modulus, CoordinateExceedsModulus),
+        (g1, g2_not_in_subgroup, NotInSubgroup),
+        (
+            [0u8; eth::G1_ENCODED_SIZE],
+            g2_not_in_subgroup,
+            NotInSubgroup,
+        ),
+    ] {
+        let input = [&pair[..], &g1[..], &g2[..]].concat();
+        assert_eq!(eth::ec_pairing(&input), Err(error));
+    }
+}
+
+#[test]
+fn test_eth_encoding_roundtrip() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        assert_eq!(eth::decode_g1(&eth::encode_g1(&p)), Ok(p));
+        let q = G2Projective::rand(&mut rng).into_affine();
+        assert_eq!(eth::decode_g2(&eth::encode_g2(&q)), Ok(q));
+    }
+
+    let g1_zero = G1Affine::zero();
+    assert_eq!(eth::encode_g1(&g1_zero), [0u8; eth::G1_ENCODED_SIZE]);
+    assert_eq!(eth::decode_g1(&[0u8; eth::G1_ENCODED_SIZE]), Ok(g1_zero));
+    let g2_zero = G2Affine::zero();
+    assert_eq!(eth::encode_g2(&g2_zero), [0u8; eth::G2_ENCODED_SIZE]);
+    assert_eq!(eth::decode_g2(&[0u8; eth::G2_ENCODED_SIZE]), Ok(g2_zero));
+}
+
+#[test]
+fn test_eth_g2_coordinate_order() {
+    // The generator of G2 as given in EIP-197, imaginary parts first.
+    let encoded = hex::decode(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+         1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+         090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+         12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    )
+    .unwrap();
+    assert_eq!(
+        eth::encode_g2(&G2Affine::generator()).as_slice(),
+        &encoded[..]
+    );
+}
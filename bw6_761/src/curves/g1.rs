@@ -1,7 +1,9 @@
+use ark_curve_utils::bw6::{linear_combination, mul_by_x};
 use ark_ec::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup,
 };
 use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField};
 
@@ -48,6 +50,39 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        // Algorithm from https://eprint.iacr.org/2022/352, where phi is the
+        // endomorphism of the `GLVConfig` below.
+        //
+        // (a, b) = (-x - 1, x^3 - x^2 - x) is a short vector of the lattice of
+        // (a, b) with a + b * LAMBDA = 0 mod r. Since a^2 - ab + b^2 is coprime
+        // to the cofactor, the kernel of [a] + phi o [b] on E(Fq) is exactly G1.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+
+        // Checks that [x + 1]P = phi([x^3 - x^2 - x]P)
+        x_p + p == Self::endomorphism(&(x3_p - x2_p - x_p))
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        // Following https://eprint.iacr.org/2020/351, the effective cofactor
+        // h(P) = [7x^2 + 89x + 130]P + phi([103x^3 - 83x^2 - 40x + 136]P)
+        // kills the part of E(Fq) outside G1, and is nonzero on G1.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+        let points = [p, x_p, x2_p, x3_p];
+
+        let h1_p = linear_combination(&[130, 89, 7], &points);
+        let h2_p = linear_combination(&[136, -40, -83, 103], &points);
+        (h1_p + Self::endomorphism(&h2_p)).into_affine()
+    }
 }
 
 impl GLVConfig for Config {
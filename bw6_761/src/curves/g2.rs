@@ -1,7 +1,9 @@
+use ark_curve_utils::bw6::{linear_combination, mul_by_x, mul_by_x_minus_1_div_3};
 use ark_ec::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup,
 };
 use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField};
 
@@ -49,6 +51,44 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        // Algorithm from https://eprint.iacr.org/2022/352, where phi is the
+        // endomorphism of the `GLVConfig` below.
+        //
+        // (a, b) = (x + 1, x^3 - x^2 + 1) is a short vector of the lattice of
+        // (a, b) with a + b * LAMBDA = 0 mod r. However 3 divides a + b, so
+        // [a] + phi o [b] also kills the 3-torsion point (0, 2), which is
+        // fixed by phi. Dividing by 1 - phi gives
+        // (c, d) = ((2a - b) / 3, (a + b) / 3) = (-9k^3 - 6k^2 + k + 1, 9k^3 + 6k^2 + 2k + 1),
+        // with k = (x - 1) / 3, whose kernel on E(Fq) is exactly G2.
+        let p = p.into_group();
+        let k_p = mul_by_x_minus_1_div_3::<super::Config, _>(&p);
+        let k2_p = mul_by_x_minus_1_div_3::<super::Config, _>(&k_p);
+        let k3_p = mul_by_x_minus_1_div_3::<super::Config, _>(&k2_p);
+        // [9k^3 + 6k^2]P
+        let u_p = linear_combination(&[9, 6], &[k3_p, k2_p]);
+
+        // Checks that [9k^3 + 6k^2 - k - 1]P = phi([9k^3 + 6k^2 + 2k + 1]P)
+        u_p - k_p - p == Self::endomorphism(&(u_p + k_p.double() + p))
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // Following https://eprint.iacr.org/2020/351, the effective cofactor
+        // h(P) = [7x^2 - 117x - 109]P + phi([103x^3 - 83x^2 - 143x + 27]P)
+        // kills the part of E(Fq) outside G2, and is nonzero on G2.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+        let points = [p, x_p, x2_p, x3_p];
+
+        let h1_p = linear_combination(&[-109, -117, 7], &points);
+        let h2_p = linear_combination(&[27, -143, -83, 103], &points);
+        (h1_p + Self::endomorphism(&h2_p)).into_affine()
+    }
 }
 
 impl GLVConfig for Config {
@@ -1,6 +1,7 @@
 use crate::*;
 use ark_algebra_test_templates::*;
-use ark_ff::Field;
+use ark_curve_constraint_tests::groups::subgroup_check_test;
+use ark_ff::{Field, Zero};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
@@ -8,3 +9,16 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<BW6_761>; msm);
 test_pairing!(pairing; crate::BW6_761);
 test_group!(g1_glv; G1Projective; glv);
 test_group!(g2_glv; G2Projective; glv);
+
+#[test]
+fn test_g1_subgroup_check_and_cofactor_clearing() {
+    subgroup_check_test::<g1::Config>(&[]);
+}
+
+#[test]
+fn test_g2_subgroup_check_and_cofactor_clearing() {
+    // The point (0, 2) has order 3 and is fixed by the endomorphism, so it
+    // must be ruled out separately from the lattice check.
+    let t = G2Affine::new_unchecked(Fq::zero(), Fq::from(2u64));
+    subgroup_check_test::<g2::Config>(&[t]);
+}
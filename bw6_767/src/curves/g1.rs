@@ -1,8 +1,11 @@
+use ark_curve_utils::bw6::{linear_combination, mul_by_x, mul_by_x_minus_1_div_3};
 use ark_ec::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField};
 
 use crate::{Fq, Fr};
 
@@ -48,6 +51,91 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        // Algorithm from https://eprint.iacr.org/2022/352, where phi is the
+        // endomorphism of the `GLVConfig` below.
+        //
+        // (a, b) = (-x - 1, x^3 - x^2 - x) is a short vector of the lattice of
+        // (a, b) with a + b * LAMBDA = 0 mod r. However 3 divides a + b, so
+        // [a] + phi o [b] also kills the 3-torsion point (0, 1), which is
+        // fixed by phi. Dividing by 1 - phi gives
+        // (c, d) = ((2a - b) / 3, (a + b) / 3) = (-9k^3 - 6k^2 - 2k - 1, 9k^3 + 6k^2 - k - 1),
+        // with k = (x - 1) / 3, whose kernel on E(Fq) is exactly G1.
+        let p = p.into_group();
+        let k_p = mul_by_x_minus_1_div_3::<super::Config, _>(&p);
+        let k2_p = mul_by_x_minus_1_div_3::<super::Config, _>(&k_p);
+        let k3_p = mul_by_x_minus_1_div_3::<super::Config, _>(&k2_p);
+        // [9k^3 + 6k^2]P
+        let u_p = linear_combination(&[9, 6], &[k3_p, k2_p]);
+
+        // Checks that [9k^3 + 6k^2 + 2k + 1]P = phi([9k^3 + 6k^2 - k - 1]P)
+        u_p + k_p.double() + p == Self::endomorphism(&(u_p - k_p - p))
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        // Computed as in https://eprint.iacr.org/2020/351, the effective cofactor
+        // h(P) = [11x^2 + 9x + 46]P + phi([31x^3 - 27x^2 - 8x + 28]P)
+        // kills the part of E(Fq) outside G1, and is nonzero on G1.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+        let points = [p, x_p, x2_p, x3_p];
+
+        let h1_p = linear_combination(&[46, 9, 11], &points);
+        let h2_p = linear_combination(&[28, -8, -27, 31], &points);
+        (h1_p + Self::endomorphism(&h2_p)).into_affine()
+    }
+}
+
+impl GLVConfig for Config {
+    /// ENDO_COEFFS = [BETA], a primitive cube root of unity in Fq
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[
+        MontFp!("45145249970874624351989341074938425649635187579061891330552522940808608853609996870081473355016347159316471246898921838485114512270394210846704144343327596979902763990851861938135223607962336094530115195266656782121333243874349260"),
+    ];
+
+    /// LAMBDA = -X^5 + 3X^4 - 3X^3 + X - 2, with X = -0xd201000000010000
+    const LAMBDA: Self::ScalarField =
+        MontFp!("793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350");
+
+    /// With X as above, the lattice of (k1, k2) with k1 + LAMBDA * k2 = 0 is
+    /// spanned by the rows of [[A, -B], [B, A + B]], where
+    /// A = (-X^3 + X^2 - X - 2) / 3,
+    /// B = (-X^3 + X^2 + 2X + 1) / 3,
+    /// A + B = (-2X^3 + 2X^2 + X - 1) / 3.
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (
+            true,
+            BigInt!("1155048275357884106335086113613464118783412807316232579754"),
+        ),
+        (
+            false,
+            BigInt!("1155048275357884106335086113613464118768280431093290937003"),
+        ),
+        (
+            true,
+            BigInt!("1155048275357884106335086113613464118768280431093290937003"),
+        ),
+        (
+            true,
+            BigInt!("2310096550715768212670172227226928237551693238409523516757"),
+        ),
+    ];
+
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G1_GENERATOR_X =
@@ -1,8 +1,11 @@
+use ark_curve_utils::bw6::{linear_combination, mul_by_x};
 use ark_ec::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
+    AffineRepr, CurveGroup,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField};
 
 use crate::{Fq, Fr};
 
@@ -49,6 +52,86 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        // Algorithm from https://eprint.iacr.org/2022/352, where phi is the
+        // endomorphism of the `GLVConfig` below.
+        //
+        // (a, b) = (x + 1, x^3 - x^2 + 1) is a short vector of the lattice of
+        // (a, b) with a + b * LAMBDA = 0 mod r. Since a^2 - ab + b^2 is coprime
+        // to the cofactor, the kernel of [a] + phi o [b] on E(Fq) is exactly G2.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+
+        // Checks that [x + 1]P = -phi([x^3 - x^2 + 1]P)
+        x_p + p == -Self::endomorphism(&(x3_p - x2_p + p))
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // Computed as in https://eprint.iacr.org/2020/351, the effective cofactor
+        // h(P) = [-11x^2 + 53x + 13]P + phi([-31x^3 + 27x^2 + 39x - 15]P)
+        // kills the part of E(Fq) outside G2, and is nonzero on G2.
+        let p = p.into_group();
+        let x_p = mul_by_x::<super::Config, _>(&p);
+        let x2_p = mul_by_x::<super::Config, _>(&x_p);
+        let x3_p = mul_by_x::<super::Config, _>(&x2_p);
+        let points = [p, x_p, x2_p, x3_p];
+
+        let h1_p = linear_combination(&[13, 53, -11], &points);
+        let h2_p = linear_combination(&[-15, 39, 27, -31], &points);
+        (h1_p + Self::endomorphism(&h2_p)).into_affine()
+    }
+}
+
+impl GLVConfig for Config {
+    /// ENDO_COEFFS = [BETA], a primitive cube root of unity in Fq
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[
+        MontFp!("45145249970874624351989341074938425649635187579061891330552522940808608853609996870081473355016347159316471246898921838485114512270394210846704144343327596979902763990851861938135223607962336094530115195266656782121333243874349260"),
+    ];
+
+    /// LAMBDA = X^5 - 3X^4 + 3X^3 - X + 1, with X = -0xd201000000010000
+    const LAMBDA: Self::ScalarField =
+        MontFp!("4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436");
+
+    /// With X as above, the lattice of (k1, k2) with k1 + LAMBDA * k2 = 0 is
+    /// spanned by the rows of [[B, -A], [A, A + B]], where
+    /// A = (-X^3 + X^2 - X - 2) / 3,
+    /// B = (-X^3 + X^2 + 2X + 1) / 3,
+    /// A + B = (-2X^3 + 2X^2 + X - 1) / 3.
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (
+            true,
+            BigInt!("1155048275357884106335086113613464118768280431093290937003"),
+        ),
+        (
+            false,
+            BigInt!("1155048275357884106335086113613464118783412807316232579754"),
+        ),
+        (
+            true,
+            BigInt!("1155048275357884106335086113613464118783412807316232579754"),
+        ),
+        (
+            true,
+            BigInt!("2310096550715768212670172227226928237551693238409523516757"),
+        ),
+    ];
+
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G2_GENERATOR_X =
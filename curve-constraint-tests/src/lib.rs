@@ -651,3 +651,463 @@ pub mod pairing {
         Ok(())
     }
 }
+
+pub mod hashing {
+    use ark_ec::{
+        hashing::{
+            map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+            HashToCurve,
+        },
+        AffineRepr, CurveGroup,
+    };
+    use ark_ff::{field_hashers::HashToField, Field, PrimeField};
+    use ark_std::vec::Vec;
+
+    extern crate std;
+    use std::{fs::File, io::BufReader};
+
+    /// Reads a field element whose coefficients over the base prime field are
+    /// written as comma-separated big-endian hex strings.
+    pub fn read_field<F: Field>(value: &serde_json::Value) -> F {
+        let elems = value
+            .as_str()
+            .unwrap()
+            .split(',')
+            .map(|c| {
+                let bytes = hex::decode(c.trim().trim_start_matches("0x")).unwrap();
+                F::BasePrimeField::from_be_bytes_mod_order(&bytes)
+            })
+            .collect::<Vec<_>>();
+        F::from_base_prime_field_elems(elems).unwrap()
+    }
+
+    /// Checks the hash-to-curve vectors in the format of RFC 9380, appendix J,
+    /// starting from the message, with the DST of the file.
+    ///
+    /// For the random-oracle suites, `P` is computed by
+    /// `MapToCurveBasedHasher<C, H2F, M2C>`. For the nonuniform suites, which
+    /// have no hasher in `ark-ec`, `P` is the cofactor-cleared image of the
+    /// single field element. `read_point` maps the coordinates of the file
+    /// to a point, e.g. from the Montgomery to the twisted Edwards model.
+    pub fn hash_to_curve_test<C, H2F, M2C>(
+        filename: &str,
+        read_point: impl Fn(C::BaseField, C::BaseField) -> C::Affine,
+    ) where
+        C: CurveGroup,
+        H2F: HashToField<C::BaseField>,
+        M2C: MapToCurve<C>,
+    {
+        M2C::check_parameters().unwrap();
+
+        let file = File::open(filename).unwrap();
+        let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+        let dst = data["dst"].as_str().unwrap().as_bytes();
+        let field_hasher = H2F::new(dst);
+        let point = |v: &serde_json::Value| read_point(read_field(&v["x"]), read_field(&v["y"]));
+
+        for v in data["vectors"].as_array().unwrap() {
+            let msg = v["msg"].as_str().unwrap().as_bytes();
+            let u = v["u"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(read_field)
+                .collect::<Vec<C::BaseField>>();
+            let p = if data["randomOracle"].as_bool().unwrap() {
+                assert_eq!(
+                    field_hasher.hash_to_field::<2>(msg),
+                    u[..],
+                    "{:?}",
+                    v["msg"]
+                );
+                let q0 = M2C::map_to_curve(u[0]).unwrap();
+                let q1 = M2C::map_to_curve(u[1]).unwrap();
+                assert_eq!(q0, point(&v["Q0"]), "{:?}", v["msg"]);
+                assert_eq!(q1, point(&v["Q1"]), "{:?}", v["msg"]);
+                let hasher = MapToCurveBasedHasher::<C, H2F, M2C>::new(dst).unwrap();
+                hasher.hash(msg).unwrap()
+            } else {
+                assert_eq!(
+                    field_hasher.hash_to_field::<1>(msg),
+                    u[..],
+                    "{:?}",
+                    v["msg"]
+                );
+                let q = M2C::map_to_curve(u[0]).unwrap();
+                assert_eq!(q, point(&v["Q"]), "{:?}", v["msg"]);
+                q.clear_cofactor()
+            };
+            assert!(p.mul_bigint(C::ScalarField::characteristic()).is_zero());
+            assert_eq!(p, point(&v["P"]), "{:?}", v["msg"]);
+        }
+    }
+}
+
+pub mod groups {
+    use ark_ec::{
+        pairing::{MillerLoopOutput, Pairing, PairingOutput},
+        short_weierstrass::{Affine, Projective, SWCurveConfig},
+        AffineRepr, CurveGroup,
+    };
+    use ark_ff::{Field, One, UniformRand, Zero};
+    use ark_serialize::{
+        CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+    };
+    use ark_std::{rand::Rng, test_rng, vec::Vec};
+
+    /// Checks `is_in_correct_subgroup_assuming_on_curve` and `clear_cofactor`
+    /// against multiplication by the order r of the subgroup: on points of the
+    /// subgroup, on random points of the curve, on these shifted by a point of
+    /// the subgroup, and on `torsion_points`, which are outside the subgroup.
+    pub fn subgroup_check_test<P: SWCurveConfig>(torsion_points: &[Affine<P>]) {
+        let mut rng = test_rng();
+        let in_subgroup = |p: &Affine<P>| p.mul_bigint(P::ScalarField::characteristic()).is_zero();
+        let check = |p: Affine<P>| {
+            let expected = in_subgroup(&p);
+            assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), expected);
+            let q = p.clear_cofactor();
+            assert!(q.is_on_curve());
+            assert!(in_subgroup(&q));
+            assert!(q.is_in_correct_subgroup_assuming_on_curve());
+            expected
+        };
+
+        assert!(check(Affine::zero()));
+        for _ in 0..10 {
+            assert!(check(Projective::<P>::rand(&mut rng).into_affine()));
+        }
+        for t in torsion_points {
+            assert!(t.is_on_curve());
+            assert!(!check(*t));
+            assert!(!check((*t + Projective::<P>::rand(&mut rng)).into_affine()));
+        }
+
+        let mut non_members = 0;
+        while non_members < 10 {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(p) = Affine::<P>::get_point_from_x_unchecked(x, rng.gen()) {
+                let q = (p + Projective::<P>::rand(&mut rng)).into_affine();
+                for p in [p, q] {
+                    if !check(p) {
+                        non_members += 1;
+                    }
+                }
+                assert!(!p.clear_cofactor().is_zero());
+            }
+        }
+    }
+
+    /// Checks a membership test for the target group of a pairing of embedding
+    /// degree 12 against exponentiation by r: on pairing outputs, on random
+    /// elements, and on elements of the cyclotomic subgroup that are not of
+    /// order r, also shifted by a pairing output. Also checks that
+    /// `deserialize` and `Validate::Yes` both accept the serialized pairing
+    /// outputs and reject the serialized non-members.
+    pub fn gt_membership_test<E: Pairing>(
+        is_in_gt: impl Fn(&E::TargetField) -> bool,
+        deserialize: impl Fn(&[u8], Compress) -> Result<PairingOutput<E>, SerializationError>,
+    ) {
+        let mut rng = test_rng();
+        let in_gt = |f: &E::TargetField| f.pow(E::ScalarField::characteristic()).is_one();
+        let serialize = |f: &E::TargetField, compress: Compress| {
+            let mut bytes = Vec::new();
+            PairingOutput::<E>(*f)
+                .serialize_with_mode(&mut bytes, compress)
+                .unwrap();
+            bytes
+        };
+
+        assert!(is_in_gt(&E::TargetField::one()));
+        assert!(!is_in_gt(&E::TargetField::zero()));
+        for _ in 0..10 {
+            let out = E::pairing(E::G1::rand(&mut rng), E::G2::rand(&mut rng)).0;
+            assert!(is_in_gt(&out));
+
+            let f = E::TargetField::rand(&mut rng);
+            let exp = E::final_exponentiation(MillerLoopOutput(f)).unwrap().0;
+            assert!(is_in_gt(&exp));
+
+            // f^((q^6 - 1)(q^2 + 1)), the easy part of the final
+            // exponentiation, is in the cyclotomic subgroup.
+            let g = f.frobenius_map(6) / f;
+            let g = g.frobenius_map(2) * g;
+            for h in [f, g, g * out] {
+                assert!(!in_gt(&h));
+                assert!(!is_in_gt(&h));
+            }
+
+            for compress in [Compress::Yes, Compress::No] {
+                let bytes = serialize(&out, compress);
+                let checked =
+                    PairingOutput::<E>::deserialize_with_mode(&bytes[..], compress, Validate::Yes)
+                        .unwrap();
+                assert_eq!(checked.0, out);
+                assert_eq!(deserialize(&bytes, compress).unwrap().0, out);
+
+                let bytes = serialize(&g, compress);
+                assert!(PairingOutput::<E>::deserialize_with_mode(
+                    &bytes[..],
+                    compress,
+                    Validate::Yes
+                )
+                .is_err());
+                assert!(deserialize(&bytes, compress).is_err());
+                let unchecked =
+                    PairingOutput::<E>::deserialize_with_mode(&bytes[..], compress, Validate::No)
+                        .unwrap();
+                assert!(!is_in_gt(&unchecked.0));
+            }
+        }
+    }
+}
+
+pub mod sec1 {
+    use ark_curve_utils::sec1::{self, Error};
+    use ark_ec::{
+        short_weierstrass::{Affine, Projective, SWCurveConfig},
+        AffineRepr, CurveGroup,
+    };
+    use ark_ff::{BigInteger, One, PrimeField, UniformRand, Zero};
+    use ark_std::{test_rng, vec::Vec};
+
+    extern crate std;
+    use std::{fs::File, io::BufReader};
+
+    fn modulus_bytes<F: PrimeField>() -> Vec<u8> {
+        let bytes = F::MODULUS.to_bytes_be();
+        bytes[bytes.len() - sec1::byte_size::<F>()..].to_vec()
+    }
+
+    /// Checks the point decoding of `ark_curve_utils::sec1` against the
+    /// encoded public keys of the file, which are either `valid` or `invalid`,
+    /// and checks that the valid ones are re-encoded to the same bytes.
+    pub fn sec1_test<P: SWCurveConfig>(filename: &str)
+    where
+        P::BaseField: PrimeField,
+    {
+        let file = File::open(filename).unwrap();
+        let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+
+        for test in data["tests"].as_array().unwrap() {
+            let public = hex::decode(test["public"].as_str().unwrap()).unwrap();
+            let valid = test["result"] == "valid";
+            let result = sec1::decode_point::<P>(&public);
+            assert_eq!(result.is_ok(), valid, "tcId {}", test["tcId"]);
+
+            if let Ok(p) = result {
+                let compress = matches!(public[0], 0x02 | 0x03);
+                assert_eq!(
+                    sec1::encode_point(&p, compress),
+                    public,
+                    "tcId {}",
+                    test["tcId"]
+                );
+            }
+        }
+    }
+
+    /// Checks the encodings of `ark_curve_utils::sec1` against the points
+    /// `[k] G` of the file, given by the big-endian scalar `k` and the
+    /// big-endian affine coordinates, and checks that malformed points and
+    /// scalars are rejected with the expected error.
+    pub fn sec1_generator_multiples_test<P: SWCurveConfig>(filename: &str)
+    where
+        P::BaseField: PrimeField,
+    {
+        let file = File::open(filename).unwrap();
+        let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+        let hex = |v: &serde_json::Value| hex::decode(v.as_str().unwrap()).unwrap();
+
+        for v in data["vectors"].as_array().unwrap() {
+            let (k_bytes, x, y) = (hex(&v["scalar"]), hex(&v["x"]), hex(&v["y"]));
+            let k = sec1::decode_scalar::<P::ScalarField>(&k_bytes).unwrap();
+            assert_eq!(sec1::encode_scalar(&k), k_bytes);
+            let p = (Affine::<P>::generator() * k).into_affine();
+
+            let uncompressed = [&[0x04][..], &x, &y].concat();
+            let tag = if y.last().unwrap() & 1 == 1 {
+                0x03
+            } else {
+                0x02
+            };
+            let compressed = [&[tag][..], &x].concat();
+            assert_eq!(
+                sec1::encode_point(&p, false),
+                uncompressed,
+                "{}",
+                v["scalar"]
+            );
+            assert_eq!(sec1::encode_point(&p, true), compressed, "{}", v["scalar"]);
+            assert_eq!(sec1::decode_point(&uncompressed), Ok(p), "{}", v["scalar"]);
+            assert_eq!(sec1::decode_point(&compressed), Ok(p), "{}", v["scalar"]);
+
+            let mut bytes = uncompressed.clone();
+            *bytes.last_mut().unwrap() ^= 1;
+            assert_eq!(sec1::decode_point::<P>(&bytes), Err(Error::NotOnCurve));
+            bytes[0] = 0x06;
+            assert_eq!(sec1::decode_point::<P>(&bytes), Err(Error::InvalidTag));
+            bytes[0] = tag;
+            assert_eq!(sec1::decode_point::<P>(&bytes), Err(Error::InvalidLength));
+            let truncated = &compressed[..compressed.len() - 1];
+            assert_eq!(
+                sec1::decode_point::<P>(truncated),
+                Err(Error::InvalidLength)
+            );
+        }
+
+        // Coordinates that are not reduced, and an x-coordinate of no point.
+        let modulus = modulus_bytes::<P::BaseField>();
+        let bytes = [&[0x02][..], &modulus].concat();
+        assert_eq!(
+            sec1::decode_point::<P>(&bytes),
+            Err(Error::CoordinateOutOfRange)
+        );
+        let p = Projective::<P>::rand(&mut test_rng()).into_affine();
+        let bytes = [&[0x04][..], &sec1::encode_be(&p.x), &modulus].concat();
+        assert_eq!(
+            sec1::decode_point::<P>(&bytes),
+            Err(Error::CoordinateOutOfRange)
+        );
+        let mut x = P::BaseField::zero();
+        while Affine::<P>::get_point_from_x_unchecked(x, false).is_some() {
+            x += P::BaseField::one();
+        }
+        let bytes = [&[0x03][..], &sec1::encode_be(&x)].concat();
+        assert_eq!(sec1::decode_point::<P>(&bytes), Err(Error::NotOnCurve));
+
+        // The point at infinity.
+        for compress in [true, false] {
+            assert_eq!(sec1::encode_point(&Affine::<P>::zero(), compress), [0x00]);
+        }
+        assert_eq!(sec1::decode_point(&[0x00]), Ok(Affine::<P>::zero()));
+        assert_eq!(
+            sec1::decode_point::<P>(&[0x00; 2]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(sec1::decode_point::<P>(&[]), Err(Error::InvalidLength));
+
+        // Scalars that are not reduced, or of the wrong length.
+        let order = modulus_bytes::<P::ScalarField>();
+        let mut order_minus_one = order.clone();
+        *order_minus_one.last_mut().unwrap() -= 1;
+        let minus_one = -P::ScalarField::one();
+        assert_eq!(sec1::encode_scalar(&minus_one), order_minus_one);
+        assert_eq!(sec1::decode_scalar(&order_minus_one), Ok(minus_one));
+        assert_eq!(
+            sec1::decode_scalar::<P::ScalarField>(&order),
+            Err(Error::ScalarOutOfRange)
+        );
+        assert_eq!(
+            sec1::decode_scalar::<P::ScalarField>(&order[1..]),
+            Err(Error::InvalidLength)
+        );
+    }
+}
+
+pub mod ecdsa {
+    use ark_curve_utils::{
+        ecdsa::{self, Error, Signature},
+        sec1,
+    };
+    use ark_ec::{short_weierstrass::SWCurveConfig, AffineRepr};
+    use ark_ff::{PrimeField, UniformRand, Zero};
+    use ark_std::{test_rng, vec::Vec};
+    use digest::{core_api::BlockSizeUser, Digest};
+
+    extern crate std;
+    use std::{fs::File, io::BufReader};
+
+    /// Checks that signatures of random keys verify and recover the public
+    /// key, and that signing with a zero secret key fails.
+    pub fn ecdsa_test<P, D>()
+    where
+        P: SWCurveConfig,
+        P::BaseField: PrimeField,
+        D: Digest + BlockSizeUser,
+    {
+        let mut rng = test_rng();
+        for i in 0..10 {
+            let secret = P::ScalarField::rand(&mut rng);
+            let public = ecdsa::public_key::<P>(&secret);
+            let message = [i as u8; 7];
+            let (sig, recovery_id) = ecdsa::sign::<P, D>(&secret, &message).unwrap();
+            assert_eq!(Signature::from_bytes(&sig.to_bytes()), Ok(sig));
+            assert!(ecdsa::verify::<P, D>(&public, &message, &sig).is_ok());
+
+            // Signing is deterministic, and both (r, s) and (r, -s) verify.
+            assert_eq!(
+                ecdsa::sign::<P, D>(&secret, &message),
+                Ok((sig, recovery_id))
+            );
+            let (low, negated) = sig.normalize_s();
+            assert!(low.is_low_s());
+            assert_eq!(negated, !sig.is_low_s());
+            assert!(ecdsa::verify::<P, D>(&public, &message, &low).is_ok());
+
+            assert_eq!(
+                ecdsa::verify::<P, D>(&public, &[i as u8; 8], &sig),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(
+                ecdsa::verify::<P, D>(&AffineRepr::zero(), &message, &sig),
+                Err(Error::InvalidPublicKey)
+            );
+
+            // Negating s flips the parity bit of the recovery id, and the
+            // other parity recovers a different key, which also verifies.
+            assert_eq!(
+                ecdsa::recover::<P, D>(&message, &sig, recovery_id),
+                Ok(public)
+            );
+            assert_eq!(
+                ecdsa::recover::<P, D>(&message, &low, recovery_id ^ u8::from(negated)),
+                Ok(public)
+            );
+            let other = ecdsa::recover::<P, D>(&message, &sig, recovery_id ^ 1).unwrap();
+            assert_ne!(other, public);
+            assert!(ecdsa::verify::<P, D>(&other, &message, &sig).is_ok());
+            assert_eq!(
+                ecdsa::recover::<P, D>(&message, &sig, 4),
+                Err(Error::InvalidRecoveryId)
+            );
+        }
+
+        assert_eq!(
+            ecdsa::sign::<P, D>(&P::ScalarField::zero(), b"message"),
+            Err(Error::InvalidSecretKey)
+        );
+    }
+
+    /// Checks the Wycheproof ECDSA verification vectors of the file, whose
+    /// signatures are encoded as `r || s`, and checks that exactly one
+    /// recovery id recovers the public key of each valid signature.
+    pub fn wycheproof_ecdsa_test<P, D>(filename: &str)
+    where
+        P: SWCurveConfig,
+        P::BaseField: PrimeField,
+        D: Digest,
+    {
+        let file = File::open(filename).unwrap();
+        let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+        let hex = |v: &serde_json::Value| hex::decode(v.as_str().unwrap()).unwrap();
+
+        for group in data["testGroups"].as_array().unwrap() {
+            let public = sec1::decode_point::<P>(&hex(&group["key"]["uncompressed"])).unwrap();
+            for test in group["tests"].as_array().unwrap() {
+                let (msg, sig) = (hex(&test["msg"]), hex(&test["sig"]));
+                let valid = test["result"] == "valid";
+                let sig = Signature::from_bytes(&sig);
+                let result = sig.and_then(|sig| ecdsa::verify::<P, D>(&public, &msg, &sig));
+                assert_eq!(result.is_ok(), valid, "tcId {}", test["tcId"]);
+
+                if valid {
+                    let sig = sig.unwrap();
+                    let ids = (0..4)
+                        .filter(|&id| ecdsa::recover::<P, D>(&msg, &sig, id) == Ok(public))
+                        .collect::<Vec<_>>();
+                    assert_eq!(ids.len(), 1, "tcId {}", test["tcId"]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Scalar multiplications by the curve parameter of BW6 curves, used by the
+//! endomorphism-based subgroup checks and cofactor clearing of their G1 and
+//! G2.
+
+use ark_ec::{
+    bw6::BW6Config,
+    short_weierstrass::{Projective, SWCurveConfig},
+    PrimeGroup,
+};
+
+/// Multiplies `p` by the curve parameter `X` of `C`.
+pub fn mul_by_x<C: BW6Config, P: SWCurveConfig>(p: &Projective<P>) -> Projective<P> {
+    let x_p = p.mul_bigint(C::X);
+    if C::X_IS_NEGATIVE {
+        -x_p
+    } else {
+        x_p
+    }
+}
+
+/// Multiplies `p` by `(X - 1) / 3`, where `X` is the curve parameter of `C`.
+pub fn mul_by_x_minus_1_div_3<C: BW6Config, P: SWCurveConfig>(p: &Projective<P>) -> Projective<P> {
+    let k_p = p.mul_bigint(C::X_MINUS_1_DIV_3);
+    if C::X_IS_NEGATIVE {
+        -k_p
+    } else {
+        k_p
+    }
+}
+
+/// Returns `sum_i [coeffs[i]]points[i]`, for small signed coefficients.
+pub fn linear_combination<P: SWCurveConfig>(
+    coeffs: &[i64],
+    points: &[Projective<P>],
+) -> Projective<P> {
+    coeffs
+        .iter()
+        .zip(points)
+        .map(|(&c, p)| {
+            let c_p = p.mul_bigint([c.unsigned_abs()]);
+            if c < 0 {
+                -c_p
+            } else {
+                c_p
+            }
+        })
+        .sum()
+}
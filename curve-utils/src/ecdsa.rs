@@ -0,0 +1,381 @@
+//! ECDSA signatures with deterministic nonces as in [RFC 6979].
+//!
+//! The functions are generic over the curve `P` and the hash function `D`,
+//! which hashes the message and instantiates the HMAC-DRBG that derives the
+//! nonces. The message representative is the leftmost `qlen` bits of the
+//! digest, where `qlen` is the bit length of the group order `n`, reduced
+//! modulo `n`. Signatures are encoded as in IEEE P1363, i.e. as the
+//! concatenation of `r` and `s` as fixed-width big-endian integers.
+//!
+//! Signing does not normalize `s`; see [`Signature::normalize_s`] for
+//! protocols that require low-S signatures.
+//!
+//! [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ark_std::{fmt, marker::PhantomData, vec, vec::Vec};
+use digest::{core_api::BlockSizeUser, Digest};
+use hmac::{Mac, SimpleHmac};
+
+use crate::sec1::{byte_size, decode_be, encode_be};
+
+/// Errors returned when decoding signatures, verifying them or recovering
+/// public keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The secret key is zero.
+    InvalidSecretKey,
+    /// The encoded signature has the wrong length.
+    InvalidLength,
+    /// `r` or `s` is zero or not smaller than the group order.
+    ScalarOutOfRange,
+    /// The public key is the point at infinity or is not on the curve.
+    InvalidPublicKey,
+    /// The recovery id is larger than 3.
+    InvalidRecoveryId,
+    /// The signature does not verify, or no public key can be recovered
+    /// from it.
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSecretKey => write!(f, "invalid secret key"),
+            Error::InvalidLength => write!(f, "invalid length"),
+            Error::ScalarOutOfRange => write!(f, "scalar is zero or exceeds group order"),
+            Error::InvalidPublicKey => write!(f, "invalid public key"),
+            Error::InvalidRecoveryId => write!(f, "invalid recovery id"),
+            Error::InvalidSignature => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+/// Left-pads or truncates a big-endian integer to `size` bytes, assuming the
+/// dropped bytes are zero.
+fn pad_be(bytes: &[u8], size: usize) -> Vec<u8> {
+    let mut out = vec![0u8; size];
+    let len = bytes.len().min(size);
+    out[size - len..].copy_from_slice(&bytes[bytes.len() - len..]);
+    out
+}
+
+/// The `bits2int` function of RFC 6979, section 2.3.2: keeps the leftmost
+/// `qlen` bits of `bytes`, and returns them as a big-endian integer of
+/// `ceil(qlen / 8)` bytes.
+fn bits2int<F: PrimeField>(bytes: &[u8]) -> Vec<u8> {
+    let qlen = F::MODULUS_BIT_SIZE as usize;
+    let rlen = byte_size::<F>();
+    let mut out = vec![0u8; rlen];
+    if bytes.len() < rlen {
+        out[rlen - bytes.len()..].copy_from_slice(bytes);
+        return out;
+    }
+    out.copy_from_slice(&bytes[..rlen]);
+    let shift = 8 * rlen - qlen;
+    if shift > 0 {
+        for i in (0..rlen).rev() {
+            let carry = if i > 0 { out[i - 1] << (8 - shift) } else { 0 };
+            out[i] = (out[i] >> shift) | carry;
+        }
+    }
+    out
+}
+
+/// Returns the message representative of `digest`, i.e. `bits2int(digest)`
+/// reduced modulo `n`.
+fn digest_to_scalar<F: PrimeField>(digest: &[u8]) -> F {
+    F::from_be_bytes_mod_order(&bits2int::<F>(digest))
+}
+
+/// Returns `x mod n` for a base field element `x`.
+fn base_to_scalar<P: SWCurveConfig>(x: &P::BaseField) -> P::ScalarField
+where
+    P::BaseField: PrimeField,
+{
+    P::ScalarField::from_be_bytes_mod_order(&x.into_bigint().to_bytes_be())
+}
+
+/// The HMAC-DRBG of RFC 6979, section 3.2, which generates the candidate
+/// nonces for a secret key and a message digest.
+struct HmacDrbg<D: Digest + BlockSizeUser> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    _hash: PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> HmacDrbg<D> {
+    fn hmac(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        let mut mac = SimpleHmac::<D>::new_from_slice(key).expect("HMAC accepts any key length");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// Steps 3.2.b to 3.2.g.
+    fn new(secret: &[u8], digest: &[u8]) -> Self {
+        let hlen = <D as Digest>::output_size();
+        let mut v = vec![0x01; hlen];
+        let mut k = vec![0x00; hlen];
+        k = Self::hmac(&k, &[&v, &[0x00], secret, digest]);
+        v = Self::hmac(&k, &[&v]);
+        k = Self::hmac(&k, &[&v, &[0x01], secret, digest]);
+        v = Self::hmac(&k, &[&v]);
+        Self {
+            k,
+            v,
+            _hash: PhantomData,
+        }
+    }
+
+    /// Step 3.2.h: returns the next candidate nonce, which lies in [1, n).
+    fn next_nonce<F: PrimeField>(&mut self) -> F {
+        let rlen = byte_size::<F>();
+        loop {
+            let mut t = Vec::with_capacity(rlen);
+            while t.len() < rlen {
+                self.v = Self::hmac(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let nonce = decode_be::<F>(&bits2int::<F>(&t)).filter(|k| !k.is_zero());
+            self.k = Self::hmac(&self.k, &[&self.v, &[0x00]]);
+            self.v = Self::hmac(&self.k, &[&self.v]);
+            if let Some(nonce) = nonce {
+                return nonce;
+            }
+        }
+    }
+}
+
+/// An ECDSA signature `(r, s)`, where both scalars are non-zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<F: PrimeField> {
+    pub r: F,
+    pub s: F,
+}
+
+impl<F: PrimeField> Signature<F> {
+    /// Decodes a signature `r || s`, rejecting scalars that are zero or not
+    /// smaller than the group order.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let size = byte_size::<F>();
+        if bytes.len() != 2 * size {
+            return Err(Error::InvalidLength);
+        }
+        let r = decode_be::<F>(&bytes[..size]).ok_or(Error::ScalarOutOfRange)?;
+        let s = decode_be::<F>(&bytes[size..]).ok_or(Error::ScalarOutOfRange)?;
+        if r.is_zero() || s.is_zero() {
+            return Err(Error::ScalarOutOfRange);
+        }
+        Ok(Self { r, s })
+    }
+
+    /// Encodes the signature as `r || s`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = encode_be(&self.r);
+        bytes.extend_from_slice(&encode_be(&self.s));
+        bytes
+    }
+
+    /// Returns whether `s` is at most `(n - 1) / 2`.
+    pub fn is_low_s(&self) -> bool {
+        self.s.into_bigint() <= F::MODULUS_MINUS_ONE_DIV_TWO
+    }
+
+    /// Returns the equivalent signature `(r, n - s)` if `s` is larger than
+    /// `(n - 1) / 2`, and the signature unchanged otherwise, together with
+    /// whether `s` was negated. Negating `s` flips the parity bit of the
+    /// recovery id.
+    pub fn normalize_s(&self) -> (Self, bool) {
+        if self.is_low_s() {
+            (*self, false)
+        } else {
+            (
+                Self {
+                    r: self.r,
+                    s: -self.s,
+                },
+                true,
+            )
+        }
+    }
+}
+
+/// Returns the public key of `secret`.
+pub fn public_key<P: SWCurveConfig>(secret: &P::ScalarField) -> Affine<P> {
+    (Affine::<P>::generator() * secret).into_affine()
+}
+
+/// Signs the digest of a message with the nonce derivation of RFC 6979,
+/// section 3.2, instantiated with `D`, rejecting a zero `secret`.
+///
+/// Returns the signature and the recovery id of [`recover_prehashed`]: bit 0
+/// is the parity of the y-coordinate of `R = [k]G`, and bit 1 is set if its
+/// x-coordinate is not smaller than `n`.
+pub fn sign_prehashed<P, D>(
+    secret: &P::ScalarField,
+    digest: &[u8],
+) -> Result<(Signature<P::ScalarField>, u8), Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    D: Digest + BlockSizeUser,
+{
+    if secret.is_zero() {
+        return Err(Error::InvalidSecretKey);
+    }
+    let e = digest_to_scalar::<P::ScalarField>(digest);
+    let mut drbg = HmacDrbg::<D>::new(&encode_be(secret), &encode_be(&e));
+    loop {
+        let k = drbg.next_nonce::<P::ScalarField>();
+        let big_r = public_key::<P>(&k);
+        let (x, y) = big_r.xy().unwrap();
+        let r = base_to_scalar::<P>(&x);
+        if r.is_zero() {
+            continue;
+        }
+        let s = k.inverse().unwrap() * (e + r * secret);
+        if s.is_zero() {
+            continue;
+        }
+        let size = byte_size::<P::BaseField>();
+        let overflow = encode_be(&x) != pad_be(&r.into_bigint().to_bytes_be(), size);
+        let recovery_id = u8::from(y.into_bigint().is_odd()) | (u8::from(overflow) << 1);
+        return Ok((Signature { r, s }, recovery_id));
+    }
+}
+
+/// Signs `message`, hashed with `D`, as in [`sign_prehashed`].
+pub fn sign<P, D>(
+    secret: &P::ScalarField,
+    message: &[u8],
+) -> Result<(Signature<P::ScalarField>, u8), Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    D: Digest + BlockSizeUser,
+{
+    sign_prehashed::<P, D>(secret, &D::digest(message))
+}
+
+/// Verifies a signature on the digest of a message.
+pub fn verify_prehashed<P>(
+    public: &Affine<P>,
+    digest: &[u8],
+    signature: &Signature<P::ScalarField>,
+) -> Result<(), Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+{
+    if public.is_zero()
+        || !public.is_on_curve()
+        || !public.is_in_correct_subgroup_assuming_on_curve()
+    {
+        return Err(Error::InvalidPublicKey);
+    }
+    if signature.r.is_zero() || signature.s.is_zero() {
+        return Err(Error::ScalarOutOfRange);
+    }
+    let e = digest_to_scalar::<P::ScalarField>(digest);
+    let w = signature.s.inverse().unwrap();
+    let x = (Affine::<P>::generator() * (e * w) + *public * (signature.r * w)).into_affine();
+    match x.xy() {
+        Some((x, _)) if base_to_scalar::<P>(&x) == signature.r => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
+
+/// Verifies a signature on `message`, hashed with `D`.
+pub fn verify<P, D>(
+    public: &Affine<P>,
+    message: &[u8],
+    signature: &Signature<P::ScalarField>,
+) -> Result<(), Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    D: Digest,
+{
+    verify_prehashed(public, &D::digest(message), signature)
+}
+
+/// Adds two big-endian integers of the same length, returning `None` on
+/// overflow.
+fn add_be(a: &[u8], b: &[u8]) -> Option<Vec<u8>> {
+    let mut sum = vec![0u8; a.len()];
+    let mut carry = 0u16;
+    for i in (0..a.len()).rev() {
+        let t = u16::from(a[i]) + u16::from(b[i]) + carry;
+        sum[i] = t as u8;
+        carry = t >> 8;
+    }
+    (carry == 0).then_some(sum)
+}
+
+/// Recovers the public key from a signature on the digest of a message and
+/// the recovery id returned by [`sign_prehashed`], as in SEC 1, section
+/// 4.1.6. The result verifies the signature whenever recovery succeeds.
+pub fn recover_prehashed<P>(
+    digest: &[u8],
+    signature: &Signature<P::ScalarField>,
+    recovery_id: u8,
+) -> Result<Affine<P>, Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+{
+    if recovery_id > 3 {
+        return Err(Error::InvalidRecoveryId);
+    }
+    if signature.r.is_zero() || signature.s.is_zero() {
+        return Err(Error::ScalarOutOfRange);
+    }
+
+    // x = r or r + n, which must be smaller than the base field modulus.
+    let size = byte_size::<P::BaseField>();
+    let r = pad_be(&signature.r.into_bigint().to_bytes_be(), size);
+    let n = pad_be(&P::ScalarField::MODULUS.to_bytes_be(), size);
+    let x_bytes = if recovery_id & 2 == 0 {
+        r
+    } else {
+        add_be(&r, &n).ok_or(Error::InvalidSignature)?
+    };
+    let x = decode_be::<P::BaseField>(&x_bytes).ok_or(Error::InvalidSignature)?;
+
+    let big_r = Affine::<P>::get_point_from_x_unchecked(x, false).ok_or(Error::InvalidSignature)?;
+    let big_r = if big_r.y.into_bigint().is_odd() == (recovery_id & 1 == 1) {
+        big_r
+    } else {
+        -big_r
+    };
+
+    let e = digest_to_scalar::<P::ScalarField>(digest);
+    let r_inv = signature.r.inverse().unwrap();
+    let public =
+        (big_r * (signature.s * r_inv) - Affine::<P>::generator() * (e * r_inv)).into_affine();
+    if public.is_zero() {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(public)
+}
+
+/// Recovers the public key from a signature on `message`, hashed with `D`, as
+/// in [`recover_prehashed`].
+pub fn recover<P, D>(
+    message: &[u8],
+    signature: &Signature<P::ScalarField>,
+    recovery_id: u8,
+) -> Result<Affine<P>, Error>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField,
+    D: Digest,
+{
+    recover_prehashed(&D::digest(message), signature, recovery_id)
+}
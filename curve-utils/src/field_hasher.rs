@@ -0,0 +1,154 @@
+//! Hashing to fields as in [RFC 9380], section 5, with `expand_message_xmd`.
+//!
+//! `ark_ff::field_hashers::DefaultFieldHasher` pads the message with `L`
+//! zero bytes instead of the input block size of the hash function, so it
+//! only agrees with the RFC when both happen to be equal, e.g. for BLS12-381
+//! with SHA-256. [`XmdFieldHasher`] pads with the block size and can be used
+//! for the suites of the other curves.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+
+use ark_ff::{field_hashers::HashToField, Field, PrimeField};
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+use digest::{core_api::BlockSizeUser, Digest};
+
+const MAX_DST_LENGTH: usize = 255;
+
+const LONG_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Returns `DST_prime = DST || I2OSP(len(DST), 1)`, hashing DSTs longer
+/// than 255 bytes as in section 5.3.3.
+fn dst_prime<H: Digest>(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = if dst.len() > MAX_DST_LENGTH {
+        H::new()
+            .chain_update(LONG_DST_PREFIX)
+            .chain_update(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+    dst_prime
+}
+
+/// `expand_message_xmd` of section 5.3.1, where `dst_prime` is computed by
+/// [`dst_prime`].
+fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst_prime: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    assert!(
+        ell <= 255,
+        "requested too many bytes from expand_message_xmd"
+    );
+    assert!(len_in_bytes <= u16::MAX as usize);
+
+    let b_0 = H::new()
+        .chain_update(vec![0u8; H::block_size()])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst_prime)
+        .finalize();
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(&b_i).map(|(l, r)| l ^ r).collect::<Vec<_>>();
+        b_i = H::new()
+            .chain_update(xor)
+            .chain_update([i as u8])
+            .chain_update(dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// `hash_to_field` with `expand_message_xmd` instantiated with `H`. As for
+/// `DefaultFieldHasher`, each base field element is derived from
+/// `L = ceil((ceil(log2(p)) + SEC_PARAM) / 8)` bytes.
+pub struct XmdFieldHasher<H: Digest + BlockSizeUser, const SEC_PARAM: usize = 128> {
+    dst_prime: Vec<u8>,
+    len_per_base_elem: usize,
+    _hash: PhantomData<H>,
+}
+
+impl<F, H, const SEC_PARAM: usize> HashToField<F> for XmdFieldHasher<H, SEC_PARAM>
+where
+    F: Field,
+    H: Digest + BlockSizeUser,
+{
+    fn new(dst: &[u8]) -> Self {
+        let p_bits = F::BasePrimeField::MODULUS_BIT_SIZE as usize;
+        Self {
+            dst_prime: dst_prime::<H>(dst),
+            len_per_base_elem: (p_bits + SEC_PARAM).div_ceil(8),
+            _hash: PhantomData,
+        }
+    }
+
+    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N] {
+        let m = F::extension_degree() as usize;
+        let len = self.len_per_base_elem;
+        let uniform_bytes = expand_message_xmd::<H>(msg, &self.dst_prime, N * m * len);
+        ark_std::array::from_fn(|i| {
+            let elems = (0..m).map(|j| {
+                let offset = len * (j + i * m);
+                F::BasePrimeField::from_be_bytes_mod_order(&uniform_bytes[offset..][..len])
+            });
+            F::from_base_prime_field_elems(elems).unwrap()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Sha256, Sha512};
+
+    extern crate std;
+    use std::string::String;
+
+    fn expand<H: Digest + BlockSizeUser>(dst: &[u8], msg: &str, len: usize) -> String {
+        hex::encode(expand_message_xmd::<H>(
+            msg.as_bytes(),
+            &dst_prime::<H>(dst),
+            len,
+        ))
+    }
+
+    /// Vectors of RFC 9380, appendix K.1 and K.3.
+    #[test]
+    fn test_expand_message_xmd() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            expand::<Sha256>(dst, "", 0x20),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            expand::<Sha256>(dst, "abc", 0x20),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        assert_eq!(
+            expand::<Sha512>(dst, "", 0x20),
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba"
+        );
+        assert_eq!(
+            expand::<Sha512>(dst, "abc", 0x20),
+            "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(
+    warnings,
+    unused,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms
+)]
+#![forbid(unsafe_code)]
+
+//! This library contains the curve-agnostic building blocks shared by the
+//! curve crates of this workspace, so that each of them does not carry its
+//! own copy:
+//! * [`field_hasher`]: hashing to fields with `expand_message_xmd` of RFC 9380.
+//! * [`bw6`]: multiplications by the curve parameter of BW6 curves.
+//! * [`ecdsa`]: ECDSA signatures with the deterministic nonces of RFC 6979.
+//! * [`pairing`]: membership in the target group of pairings.
+//! * [`sec1`]: the point and scalar encodings of SEC 1.
+//! * [`serialization`]: the flagged point encoding of BLS12 curves.
+
+pub mod bw6;
+pub mod ecdsa;
+pub mod field_hasher;
+pub mod pairing;
+pub mod sec1;
+pub mod serialization;
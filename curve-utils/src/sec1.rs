@@ -0,0 +1,158 @@
+//! Point and scalar encodings of [SEC 1], section 2.3, for short Weierstrass
+//! curves over prime fields.
+//!
+//! A point is encoded as `0x04 || x || y` when uncompressed, as
+//! `0x02 || x` or `0x03 || x` when compressed, depending on the parity of
+//! `y`, and the point at infinity as the single byte `0x00`. Field elements
+//! and scalars are encoded as big-endian integers of [`byte_size`] bytes.
+//!
+//! [SEC 1]: https://www.secg.org/sec1-v2.pdf
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{fmt, vec::Vec};
+
+const TAG_IDENTITY: u8 = 0x00;
+const TAG_COMPRESSED_EVEN: u8 = 0x02;
+const TAG_COMPRESSED_ODD: u8 = 0x03;
+const TAG_UNCOMPRESSED: u8 = 0x04;
+
+/// Errors returned when decoding points or scalars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input has the wrong length for its tag.
+    InvalidLength,
+    /// The leading byte is not a valid point tag.
+    InvalidTag,
+    /// A coordinate is not smaller than the base field modulus.
+    CoordinateOutOfRange,
+    /// The decoded point does not satisfy the curve equation.
+    NotOnCurve,
+    /// The scalar is not smaller than the group order.
+    ScalarOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidLength => write!(f, "invalid length"),
+            Error::InvalidTag => write!(f, "invalid point tag"),
+            Error::CoordinateOutOfRange => write!(f, "coordinate exceeds modulus"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::ScalarOutOfRange => write!(f, "scalar exceeds group order"),
+        }
+    }
+}
+
+/// Size in bytes of an encoded element of `F`.
+pub fn byte_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+/// Encodes an element of `F` as a big-endian integer of [`byte_size`] bytes.
+pub fn encode_be<F: PrimeField>(f: &F) -> Vec<u8> {
+    let bytes = f.into_bigint().to_bytes_be();
+    bytes[bytes.len() - byte_size::<F>()..].to_vec()
+}
+
+/// Decodes a big-endian integer of [`byte_size`] bytes, rejecting values that
+/// are not smaller than the modulus of `F`.
+pub fn decode_be<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    if bytes.len() != byte_size::<F>() {
+        return None;
+    }
+    let f = F::from_be_bytes_mod_order(bytes);
+    (encode_be(&f) == bytes).then_some(f)
+}
+
+fn is_odd<F: PrimeField>(f: &F) -> bool {
+    f.into_bigint().is_odd()
+}
+
+/// Encodes a point, compressed or uncompressed. The point at infinity is
+/// encoded as `0x00` in both cases.
+pub fn encode_point<P: SWCurveConfig>(p: &Affine<P>, compress: bool) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let Some((x, y)) = p.xy() else {
+        return ark_std::vec![TAG_IDENTITY];
+    };
+    let mut bytes = Vec::with_capacity(1 + 2 * byte_size::<P::BaseField>());
+    if compress {
+        bytes.push(if is_odd(&y) {
+            TAG_COMPRESSED_ODD
+        } else {
+            TAG_COMPRESSED_EVEN
+        });
+        bytes.extend_from_slice(&encode_be(&x));
+    } else {
+        bytes.push(TAG_UNCOMPRESSED);
+        bytes.extend_from_slice(&encode_be(&x));
+        bytes.extend_from_slice(&encode_be(&y));
+    }
+    bytes
+}
+
+/// Decodes a point encoded by [`encode_point`], checking that the
+/// coordinates are reduced and that the point is on the curve. The point is
+/// not checked to be in the prime-order subgroup.
+///
+/// The hybrid encodings `0x06` and `0x07` are rejected.
+pub fn decode_point<P: SWCurveConfig>(bytes: &[u8]) -> Result<Affine<P>, Error>
+where
+    P::BaseField: PrimeField,
+{
+    let size = byte_size::<P::BaseField>();
+    let (&tag, rest) = bytes.split_first().ok_or(Error::InvalidLength)?;
+    match tag {
+        TAG_IDENTITY => {
+            if !rest.is_empty() {
+                return Err(Error::InvalidLength);
+            }
+            Ok(Affine::zero())
+        },
+        TAG_COMPRESSED_EVEN | TAG_COMPRESSED_ODD => {
+            if rest.len() != size {
+                return Err(Error::InvalidLength);
+            }
+            let x = decode_be(rest).ok_or(Error::CoordinateOutOfRange)?;
+            let p = Affine::<P>::get_point_from_x_unchecked(x, false).ok_or(Error::NotOnCurve)?;
+            if is_odd(&p.y) == (tag == TAG_COMPRESSED_ODD) {
+                Ok(p)
+            } else {
+                Ok(-p)
+            }
+        },
+        TAG_UNCOMPRESSED => {
+            if rest.len() != 2 * size {
+                return Err(Error::InvalidLength);
+            }
+            let x = decode_be(&rest[..size]).ok_or(Error::CoordinateOutOfRange)?;
+            let y = decode_be(&rest[size..]).ok_or(Error::CoordinateOutOfRange)?;
+            let p = Affine::new_unchecked(x, y);
+            if !p.is_on_curve() {
+                return Err(Error::NotOnCurve);
+            }
+            Ok(p)
+        },
+        _ => Err(Error::InvalidTag),
+    }
+}
+
+/// Encodes a scalar as a big-endian integer of [`byte_size`] bytes.
+pub fn encode_scalar<F: PrimeField>(s: &F) -> Vec<u8> {
+    encode_be(s)
+}
+
+/// Decodes a scalar encoded by [`encode_scalar`], rejecting values that are
+/// not smaller than the group order.
+pub fn decode_scalar<F: PrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    if bytes.len() != byte_size::<F>() {
+        return Err(Error::InvalidLength);
+    }
+    decode_be(bytes).ok_or(Error::ScalarOutOfRange)
+}
@@ -1,9 +1,21 @@
-use ark_ec::{short_weierstrass::Affine, AffineRepr};
-use ark_ff::{BigInteger384, PrimeField};
+//! The flagged point encoding of the [Zcash BLS12-381 library], for BLS12
+//! curves whose base field elements fit in 48 bytes.
+//!
+//! A base field element is encoded as a 48-byte big-endian integer, and an
+//! `Fq2` element `c0 + c1 * u` as `c1 || c0`. The three most significant bits
+//! of the first byte hold, in order, the compression flag, the infinity flag
+//! and, for compressed points, whether `y` is the lexicographically largest of
+//! `y` and `-y`.
+//!
+//! [Zcash BLS12-381 library]: https://github.com/zkcrypto/bls12_381
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{BigInteger384, Fp2, Fp2Config, PrimeField};
 use ark_serialize::SerializationError;
 
-use crate::{g1::Config as G1Config, g2::Config as G2Config, Fq, Fq2, G1Affine, G2Affine};
-
 pub const G1_SERIALIZED_SIZE: usize = 48;
 pub const G2_SERIALIZED_SIZE: usize = 96;
 
@@ -58,7 +70,9 @@ impl EncodingFlags {
     }
 }
 
-pub(crate) fn deserialize_fq(bytes: [u8; 48]) -> Option<Fq> {
+/// Decodes a 48-byte big-endian base field element, rejecting values that
+/// are not smaller than the modulus.
+pub fn deserialize_fq<F: PrimeField<BigInt = BigInteger384>>(bytes: [u8; 48]) -> Option<F> {
     let mut tmp = BigInteger384::new([0, 0, 0, 0, 0, 0]);
 
     // Note: The following unwraps are if the compiler cannot convert
@@ -71,10 +85,11 @@ pub(crate) fn deserialize_fq(bytes: [u8; 48]) -> Option<Fq> {
     tmp.0[1] = u64::from_be_bytes(<[u8; 8]>::try_from(&bytes[32..40]).unwrap());
     tmp.0[0] = u64::from_be_bytes(<[u8; 8]>::try_from(&bytes[40..48]).unwrap());
 
-    Fq::from_bigint(tmp)
+    F::from_bigint(tmp)
 }
 
-pub(crate) fn serialize_fq(field: Fq) -> [u8; 48] {
+/// Encodes a base field element as a 48-byte big-endian integer.
+pub fn serialize_fq<F: PrimeField<BigInt = BigInteger384>>(field: F) -> [u8; 48] {
     let mut result = [0u8; 48];
 
     let rep = field.into_bigint();
@@ -100,9 +115,14 @@ fn read_bytes_with_offset(bytes: &[u8], offset: usize, mask: bool) -> [u8; G1_SE
     tmp
 }
 
-pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
-    mut reader: R,
-) -> Result<Affine<G1Config>, ark_serialize::SerializationError> {
+/// Reads a compressed point of a curve over the base field. The point is not
+/// checked to be in the prime-order subgroup.
+pub fn read_g1_compressed<P, R>(mut reader: R) -> Result<Affine<P>, SerializationError>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField<BigInt = BigInteger384>,
+    R: ark_serialize::Read,
+{
     let mut bytes = [0u8; G1_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -126,19 +146,24 @@ pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
             return Err(SerializationError::InvalidData);
         }
 
-        return Ok(G1Affine::zero());
+        return Ok(Affine::zero());
     }
 
     let x = deserialize_fq(x_bytes).ok_or(SerializationError::InvalidData)?;
-    let p = G1Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
+    let p = Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
         .ok_or(SerializationError::InvalidData)?;
 
     Ok(p)
 }
 
-pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
-    mut reader: R,
-) -> Result<Affine<G1Config>, ark_serialize::SerializationError> {
+/// Reads an uncompressed point of a curve over the base field. The point is
+/// not checked to be on the curve nor in the prime-order subgroup.
+pub fn read_g1_uncompressed<P, R>(mut reader: R) -> Result<Affine<P>, SerializationError>
+where
+    P: SWCurveConfig,
+    P::BaseField: PrimeField<BigInt = BigInteger384>,
+    R: ark_serialize::Read,
+{
     let mut bytes = [0u8; 2 * G1_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -159,21 +184,27 @@ pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
         if x_bytes != [0u8; 48] || y_bytes != [0u8; 48] {
             return Err(SerializationError::InvalidData);
         }
-        return Ok(G1Affine::zero());
+        return Ok(Affine::zero());
     }
 
     // Attempt to obtain the x-coordinate
     let x = deserialize_fq(x_bytes).ok_or(SerializationError::InvalidData)?;
     // Attempt to obtain the y-coordinate
     let y = deserialize_fq(y_bytes).ok_or(SerializationError::InvalidData)?;
-    let p = G1Affine::new_unchecked(x, y);
+    let p = Affine::new_unchecked(x, y);
 
     Ok(p)
 }
 
-pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
-    mut reader: R,
-) -> Result<Affine<G2Config>, ark_serialize::SerializationError> {
+/// Reads a compressed point of a curve over the quadratic extension of the
+/// base field. The point is not checked to be in the prime-order subgroup.
+pub fn read_g2_compressed<P, C, R>(mut reader: R) -> Result<Affine<P>, SerializationError>
+where
+    P: SWCurveConfig<BaseField = Fp2<C>>,
+    C: Fp2Config,
+    C::Fp: PrimeField<BigInt = BigInteger384>,
+    R: ark_serialize::Read,
+{
     let mut bytes = [0u8; G2_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -194,23 +225,30 @@ pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
         if xc1_bytes != [0u8; 48] || xc0_bytes != [0u8; 48] {
             return Err(SerializationError::InvalidData);
         }
-        return Ok(G2Affine::zero());
+        return Ok(Affine::zero());
     }
 
     // Attempt to obtain the x-coordinate
     let xc1 = deserialize_fq(xc1_bytes).ok_or(SerializationError::InvalidData)?;
     let xc0 = deserialize_fq(xc0_bytes).ok_or(SerializationError::InvalidData)?;
-    let x = Fq2::new(xc0, xc1);
+    let x = Fp2::new(xc0, xc1);
 
-    let p = G2Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
+    let p = Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
         .ok_or(SerializationError::InvalidData)?;
 
     Ok(p)
 }
 
-pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
-    mut reader: R,
-) -> Result<Affine<G2Config>, ark_serialize::SerializationError> {
+/// Reads an uncompressed point of a curve over the quadratic extension of the
+/// base field. The point is not checked to be on the curve nor in the
+/// prime-order subgroup.
+pub fn read_g2_uncompressed<P, C, R>(mut reader: R) -> Result<Affine<P>, SerializationError>
+where
+    P: SWCurveConfig<BaseField = Fp2<C>>,
+    C: Fp2Config,
+    C::Fp: PrimeField<BigInt = BigInteger384>,
+    R: ark_serialize::Read,
+{
     let mut bytes = [0u8; 2 * G2_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -238,7 +276,7 @@ pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
         {
             return Err(SerializationError::InvalidData);
         }
-        return Ok(G2Affine::zero());
+        return Ok(Affine::zero());
     }
 
     let xc1 = deserialize_fq(xc1_bytes).ok_or(SerializationError::InvalidData)?;
@@ -247,12 +285,12 @@ pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
     let yc0 = deserialize_fq(yc0_bytes).ok_or(SerializationError::InvalidData)?;
 
     // Attempt to obtain the x-coordinate
-    let x = Fq2::new(xc0, xc1);
+    let x = Fp2::new(xc0, xc1);
 
     // Attempt to obtain the y-coordinate
-    let y = Fq2::new(yc0, yc1);
+    let y = Fp2::new(yc0, yc1);
 
-    let p = G2Affine::new_unchecked(x, y);
+    let p = Affine::new_unchecked(x, y);
 
     Ok(p)
 }
@@ -1,9 +1,17 @@
 use crate::{Fq, Fr};
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, MontgomeryAffine, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use sha2::Sha512;
+
+pub mod x25519;
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +20,23 @@ pub type EdwardsAffine = Affine<Curve25519Config>;
 pub type EdwardsProjective = Projective<Curve25519Config>;
 pub type NonZeroMontgomeryAffine = MontgomeryAffine<Curve25519Config>;
 
+/// Hashes to the curve with the `curve25519_XMD:SHA-512_ELL2_RO_` suite, with
+/// the DST passed to `HashToCurve::new`. The suite outputs Montgomery
+/// coordinates (s, t), which correspond to (s / t, (s - 1) / (s + 1)) in the
+/// twisted Edwards model used here.
+pub type Hasher = MapToCurveBasedHasher<
+    EdwardsProjective,
+    XmdFieldHasher<Sha512>,
+    Elligator2Map<Curve25519Config>,
+>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "curve25519_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "curve25519_XMD:SHA-512_ELL2_NU_";
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Curve25519Config;
 
@@ -59,6 +84,21 @@ impl MontCurveConfig for Curve25519Config {
     type TECurveConfig = Curve25519Config;
 }
 
+// Parameters of the curve25519_XMD:SHA-512_ELL2_RO_ suite from
+// [RFC 9380, section 8.5](https://www.rfc-editor.org/rfc/rfc9380.html#name-curve25519).
+// The map lands on the Montgomery curve and is carried over to the twisted
+// Edwards form by the rational map of RFC 9380, appendix D.
+impl Elligator2Config for Curve25519Config {
+    /// Z = 2
+    const Z: Fq = MontFp!("2");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 = 1
+    const ONE_OVER_COEFF_B_SQUARE: Fq = MontFp!("1");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B = 486662
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("486662");
+}
+
 /// GENERATOR_X =
 /// 38213832894368730265794714087330135568483813637251082400757400312561599933396
 pub const GENERATOR_X: Fq =
@@ -0,0 +1,152 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::{hashing::curve_maps::elligator2::Elligator2Map, AffineRepr, CurveGroup};
+use ark_ff::{AdditiveGroup, BigInt, One, UniformRand};
+use ark_std::{rand::Rng, test_rng};
+use sha2::Sha512;
+
+test_group!(te; EdwardsProjective; te);
+
+/// The RFC 9380 vectors are given in Montgomery coordinates (s, t), which are
+/// sent to the twisted Edwards form by (s, t) -> (s / t, (s - 1) / (s + 1)).
+fn read_point(s: Fq, t: Fq) -> EdwardsAffine {
+    let p = EdwardsAffine::new_unchecked(s / t, (s - Fq::one()) / (s + Fq::one()));
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<Curve25519Config>>(
+        "./src/curves/tests/curve25519_XMD-SHA-512_ELL2_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<Curve25519Config>>(
+        "./src/curves/tests/curve25519_XMD-SHA-512_ELL2_NU_.json",
+        read_point,
+    );
+}
+
+fn read_key(hex_str: &str) -> [u8; x25519::KEY_SIZE] {
+    hex::decode(hex_str).unwrap().try_into().unwrap()
+}
+
+/// The test vectors of RFC 7748, section 5.2.
+#[test]
+fn test_x25519() {
+    let vectors = [
+        (
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+        ),
+        (
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+        ),
+    ];
+    for (scalar, u, expected) in vectors {
+        assert_eq!(
+            x25519::x25519(&read_key(scalar), &read_key(u)),
+            read_key(expected)
+        );
+    }
+}
+
+/// The iterated test vectors of RFC 7748, section 5.2, up to 1000 iterations.
+#[test]
+fn test_x25519_iterated() {
+    let mut k = x25519::BASEPOINT;
+    let mut u = x25519::BASEPOINT;
+    for i in 1..=1000 {
+        let r = x25519::x25519(&k, &u);
+        u = k;
+        k = r;
+        if i == 1 {
+            assert_eq!(
+                k,
+                read_key("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+            );
+        }
+    }
+    assert_eq!(
+        k,
+        read_key("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+    );
+}
+
+/// The Diffie-Hellman example of RFC 7748, section 6.1.
+#[test]
+fn test_x25519_diffie_hellman() {
+    let alice = read_key("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob = read_key("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let alice_public = x25519::x25519(&alice, &x25519::BASEPOINT);
+    let bob_public = x25519::x25519(&bob, &x25519::BASEPOINT);
+    assert_eq!(
+        alice_public,
+        read_key("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+    );
+    assert_eq!(
+        bob_public,
+        read_key("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+    );
+    let shared = read_key("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    assert_eq!(x25519::x25519(&alice, &bob_public), shared);
+    assert_eq!(x25519::x25519(&bob, &alice_public), shared);
+}
+
+#[test]
+fn test_x25519_ladder() {
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        let p = EdwardsProjective::rand(&mut rng).into_affine();
+        let k: [u8; x25519::KEY_SIZE] = rng.gen();
+        let k = x25519::clamp_scalar(&k);
+
+        // The u-coordinate of a point (x, y) in twisted Edwards form is
+        // (1 + y) / (1 - y).
+        let to_u = |p: EdwardsAffine| (Fq::one() + p.y) / (Fq::one() - p.y);
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(k.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let q = p.mul_bigint(BigInt::new(limbs)).into_affine();
+        assert_eq!(x25519::ladder(&k, &to_u(p)), to_u(q));
+    }
+
+    // Small-order inputs lead to the all-zero output.
+    let k: [u8; x25519::KEY_SIZE] = rng.gen();
+    assert_eq!(x25519::x25519(&k, &[0u8; 32]), [0u8; 32]);
+    assert_eq!(x25519::ladder(&k, &Fq::ZERO), Fq::ZERO);
+}
+
+#[test]
+fn test_x25519_non_canonical_u() {
+    let mut rng = test_rng();
+    let k: [u8; x25519::KEY_SIZE] = rng.gen();
+
+    // The top bit is masked.
+    let mut u = x25519::BASEPOINT;
+    u[31] |= 0x80;
+    assert_eq!(
+        x25519::x25519(&k, &u),
+        x25519::x25519(&k, &x25519::BASEPOINT)
+    );
+
+    // q + 9 is reduced to 9.
+    let mut u = [0xff; x25519::KEY_SIZE];
+    u[0] = 0xf6;
+    u[31] = 0x7f;
+    assert_eq!(x25519::decode_u_coordinate(&u), Fq::from(9u64));
+    assert_eq!(
+        x25519::x25519(&k, &u),
+        x25519::x25519(&k, &x25519::BASEPOINT)
+    );
+}
@@ -0,0 +1,122 @@
+//! The X25519 function of [RFC 7748], computed with an x-only Montgomery
+//! ladder on curve25519.
+//!
+//! Scalars and u-coordinates are encoded as 32-byte little-endian strings.
+//! Scalars are clamped before use, and the most significant bit of an encoded
+//! u-coordinate is ignored. Non-canonical u-coordinates, i.e. values in
+//! `[q, 2^255)`, are accepted and reduced modulo q.
+//!
+//! The ladder performs the same sequence of field operations for every scalar
+//! and swaps its operands with masks rather than branches. The field
+//! arithmetic itself is that of `ark-ff`, which makes no constant-time
+//! guarantees.
+//!
+//! [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use ark_ff::{AdditiveGroup, BigInt, BigInteger, Field, MontFp, PrimeField};
+
+use crate::Fq;
+
+/// Size in bytes of an encoded scalar or u-coordinate.
+pub const KEY_SIZE: usize = 32;
+
+/// The encoded u-coordinate of the base point, u = 9.
+pub const BASEPOINT: [u8; KEY_SIZE] = {
+    let mut bytes = [0u8; KEY_SIZE];
+    bytes[0] = 9;
+    bytes
+};
+
+/// A24 = (A - 2) / 4 = 121665
+const A24: Fq = MontFp!("121665");
+
+/// Clamps a scalar as in RFC 7748, section 5: the three least significant
+/// bits are cleared, bit 255 is cleared and bit 254 is set.
+pub fn clamp_scalar(scalar: &[u8; KEY_SIZE]) -> [u8; KEY_SIZE] {
+    let mut k = *scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    k
+}
+
+/// Decodes a u-coordinate, masking the most significant bit and reducing the
+/// result modulo q.
+pub fn decode_u_coordinate(bytes: &[u8; KEY_SIZE]) -> Fq {
+    let mut u = *bytes;
+    u[31] &= 127;
+    Fq::from_le_bytes_mod_order(&u)
+}
+
+/// Encodes a u-coordinate as a 32-byte little-endian string.
+pub fn encode_u_coordinate(u: &Fq) -> [u8; KEY_SIZE] {
+    let mut bytes = [0u8; KEY_SIZE];
+    bytes.copy_from_slice(&u.into_bigint().to_bytes_le());
+    bytes
+}
+
+/// Swaps `a` and `b` if `swap` is 1, and leaves them unchanged if it is 0,
+/// without branching on `swap`.
+fn cswap(swap: u64, a: &mut Fq, b: &mut Fq) {
+    let mask = swap.wrapping_neg();
+    for (a, b) in (a.0).0.iter_mut().zip((b.0).0.iter_mut()) {
+        let t = mask & (*a ^ *b);
+        *a ^= t;
+        *b ^= t;
+    }
+}
+
+/// Computes the u-coordinate of [k]P from the u-coordinate of P, where `k` is
+/// a little-endian scalar whose 255 least significant bits are used, with the
+/// Montgomery ladder of RFC 7748, section 5.
+///
+/// The scalar is used as is, without clamping.
+pub fn ladder(k: &[u8; KEY_SIZE], u: &Fq) -> Fq {
+    let x_1 = *u;
+    let mut x_2 = Fq::ONE;
+    let mut z_2 = Fq::ZERO;
+    let mut x_3 = *u;
+    let mut z_3 = Fq::ONE;
+    let mut swap = 0u64;
+
+    for t in (0..255).rev() {
+        let k_t = u64::from((k[t / 8] >> (t % 8)) & 1);
+        swap ^= k_t;
+        cswap(swap, &mut x_2, &mut x_3);
+        cswap(swap, &mut z_2, &mut z_3);
+        swap = k_t;
+
+        let a = x_2 + z_2;
+        let aa = a.square();
+        let b = x_2 - z_2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x_3 + z_3;
+        let d = x_3 - z_3;
+        let da = d * a;
+        let cb = c * b;
+        x_3 = (da + cb).square();
+        z_3 = x_1 * (da - cb).square();
+        x_2 = aa * bb;
+        z_2 = e * (aa + A24 * e);
+    }
+    cswap(swap, &mut x_2, &mut x_3);
+    cswap(swap, &mut z_2, &mut z_3);
+
+    // z_2^(q - 2) is the inverse of z_2, and 0 if z_2 = 0.
+    let mut q_minus_2 = Fq::MODULUS;
+    q_minus_2.sub_with_borrow(&BigInt::from(2u64));
+    x_2 * z_2.pow(q_minus_2)
+}
+
+/// The X25519 function: multiplies the point with u-coordinate `u` by the
+/// clamped `scalar`, and returns the encoded u-coordinate of the result.
+///
+/// `x25519(scalar, &BASEPOINT)` computes the public key of `scalar`. As
+/// described in RFC 7748, section 6.1, callers performing Diffie-Hellman may
+/// want to reject an all-zero shared secret, which results from a peer
+/// public key of small order.
+pub fn x25519(scalar: &[u8; KEY_SIZE], u: &[u8; KEY_SIZE]) -> [u8; KEY_SIZE] {
+    let k = clamp_scalar(scalar);
+    encode_u_coordinate(&ladder(&k, &decode_u_coordinate(u)))
+}
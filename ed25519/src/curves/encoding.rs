@@ -0,0 +1,105 @@
+//! Point encoding of [RFC 8032, section 5.1.2].
+//!
+//! A point `(x, y)` is encoded as the 32-byte little-endian encoding of `y`,
+//! with the most significant bit of the last byte set to the least
+//! significant bit of `x`.
+//!
+//! Decoding follows [RFC 8032, section 5.1.3] in [`Mode::Strict`], and the
+//! relaxed rules of [ZIP-215] in [`Mode::Zip215`], which accept non-canonical
+//! encodings of `y` and the sign bit set for `x = 0`. Neither mode checks that
+//! the point is in the prime-order subgroup.
+//!
+//! [RFC 8032, section 5.1.2]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.2
+//! [RFC 8032, section 5.1.3]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.3
+//! [ZIP-215]: https://zips.z.cash/zip-0215
+
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_std::fmt;
+
+use crate::{EdwardsAffine, EdwardsConfig, Fq};
+
+/// Size in bytes of an encoded point.
+pub const ENCODED_SIZE: usize = 32;
+
+const SIGN_BIT: u8 = 0x80;
+
+/// Rules applied when decoding a point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// The rules of RFC 8032: `y` must be smaller than the modulus, and the
+    /// sign bit must not be set when `x = 0`.
+    Strict,
+    /// The rules of ZIP-215: `y` may be any 255-bit integer and is reduced
+    /// modulo q, and the sign bit is ignored when `x = 0`.
+    Zip215,
+}
+
+/// Errors returned when decoding points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The encoded `y` is not smaller than the base field modulus.
+    NonCanonicalY,
+    /// The sign bit is set but `x = 0`.
+    NonCanonicalSign,
+    /// No `x` satisfies the curve equation for the encoded `y`.
+    NotOnCurve,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonCanonicalY => write!(f, "y coordinate exceeds modulus"),
+            Error::NonCanonicalSign => write!(f, "sign bit is set for x = 0"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+        }
+    }
+}
+
+fn is_odd(f: &Fq) -> bool {
+    f.into_bigint().is_odd()
+}
+
+/// Encodes a point as the little-endian encoding of `y` with the parity of `x`
+/// in the top bit.
+pub fn encode_point(p: &EdwardsAffine) -> [u8; ENCODED_SIZE] {
+    let mut bytes = [0u8; ENCODED_SIZE];
+    bytes.copy_from_slice(&p.y.into_bigint().to_bytes_le());
+    if is_odd(&p.x) {
+        bytes[ENCODED_SIZE - 1] |= SIGN_BIT;
+    }
+    bytes
+}
+
+/// Decodes a point encoded by [`encode_point`], following the rules of `mode`.
+///
+/// The result is on the curve, but may have a component of small order; see
+/// [`crate::is_small_order`] and [`crate::is_torsion_free`].
+pub fn decode_point(bytes: &[u8; ENCODED_SIZE], mode: Mode) -> Result<EdwardsAffine, Error> {
+    let mut y_bytes = *bytes;
+    let sign = y_bytes[ENCODED_SIZE - 1] & SIGN_BIT != 0;
+    y_bytes[ENCODED_SIZE - 1] &= !SIGN_BIT;
+
+    let y = Fq::from_le_bytes_mod_order(&y_bytes);
+    if mode == Mode::Strict && y.into_bigint().to_bytes_le() != y_bytes {
+        return Err(Error::NonCanonicalY);
+    }
+
+    // x^2 = (y^2 - 1) / (d * y^2 + 1), where the denominator cannot vanish
+    // since -d is not a square.
+    let y2 = y.square();
+    let u = y2 - Fq::one();
+    let v = EdwardsConfig::COEFF_D * y2 + Fq::one();
+    let x = (u * v.inverse().ok_or(Error::NotOnCurve)?)
+        .sqrt()
+        .ok_or(Error::NotOnCurve)?;
+
+    if x.is_zero() {
+        if sign && mode == Mode::Strict {
+            return Err(Error::NonCanonicalSign);
+        }
+        return Ok(EdwardsAffine::new_unchecked(x, y));
+    }
+    let x = if is_odd(&x) == sign { x } else { -x };
+    Ok(EdwardsAffine::new_unchecked(x, y))
+}
@@ -1,9 +1,21 @@
 use crate::{Fq, Fr};
+use ark_curve25519::Curve25519Config;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::{elligator2::Elligator2Map as Curve25519Map, parity},
+        map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+        HashToCurveError,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+    AffineRepr,
 };
-use ark_ff::MontFp;
+use ark_ff::{AdditiveGroup, Field, MontFp, One, Zero};
+use sha2::Sha512;
+
+pub mod encoding;
+pub mod signature;
 
 #[cfg(test)]
 mod tests;
@@ -60,6 +72,58 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+/// The Elligator 2 map of the edwards25519_XMD:SHA-512_ELL2_RO_ and
+/// edwards25519_XMD:SHA-512_ELL2_NU_ suites from
+/// [RFC 9380, section 8.5](https://www.rfc-editor.org/rfc/rfc9380.html#name-edwards25519).
+///
+/// The field element is first mapped to curve25519 in Montgomery form, and
+/// then sent to edwards25519 by the birational map of RFC 9380, appendix D:
+/// (s, t) -> (sqrt(-486664) * s / t, (s - 1) / (s + 1)),
+/// where the exceptional cases t = 0 and s = -1 are sent to the identity.
+pub struct Elligator2Map;
+
+impl MapToCurve<EdwardsProjective> for Elligator2Map {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Curve25519Map::<Curve25519Config>::check_parameters()?;
+        debug_assert_eq!(
+            SQRT_MINUS_486664.square(),
+            MontFp!("-486664"),
+            "SQRT_MINUS_486664 is not a square root of -486664"
+        );
+        debug_assert!(
+            !parity(&SQRT_MINUS_486664),
+            "sgn0(SQRT_MINUS_486664) should be 0"
+        );
+        Ok(())
+    }
+
+    fn map_to_curve(element: Fq) -> Result<EdwardsAffine, HashToCurveError> {
+        // The twisted Edwards form of curve25519 is
+        // 486664 * v^2 + w^2 = 1 + 486660 * v^2 * w^2,
+        // and the Elligator 2 map of `Curve25519Config` already applies
+        // (s, t) -> (s / t, (s - 1) / (s + 1)) to reach it, including the
+        // exceptional cases. Scaling v by sqrt(-486664) yields edwards25519.
+        let p = Curve25519Map::<Curve25519Config>::map_to_curve(element)?;
+        Ok(EdwardsAffine::new_unchecked(p.x * SQRT_MINUS_486664, p.y))
+    }
+}
+
+/// Hashes to the curve with the `edwards25519_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher = MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "edwards25519_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "edwards25519_XMD:SHA-512_ELL2_NU_";
+
+/// SQRT_MINUS_486664 = sqrt(-486664), the root with sgn0 = 0 =
+/// 6853475219497561581579357271197624642482790079785650197046958215289687604742
+pub const SQRT_MINUS_486664: Fq =
+    MontFp!("6853475219497561581579357271197624642482790079785650197046958215289687604742");
+
 /// GENERATOR_X =
 /// 15112221349535400772501151409588531511454012693041857206046113283949847762202
 pub const GENERATOR_X: Fq =
@@ -70,3 +134,156 @@ pub const GENERATOR_X: Fq =
 /// 46316835694926478169428394003475163141307993866256225615783033603165251855960
 pub const GENERATOR_Y: Fq =
     MontFp!("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+
+/// Multiplies `p` by the cofactor 8 with three doublings.
+pub fn mul_by_cofactor(p: &EdwardsProjective) -> EdwardsProjective {
+    let mut res = *p;
+    res.double_in_place().double_in_place().double_in_place();
+    res
+}
+
+/// Returns whether `p` is one of the 8 points of `SMALL_ORDER_POINTS`, i.e.
+/// whether [8]P is the identity. This only takes three doublings.
+///
+/// RFC 8032 and ZIP-215 verifiers can use it to reject small-order public keys
+/// and commitments.
+pub fn is_small_order(p: &EdwardsAffine) -> bool {
+    mul_by_cofactor(&p.into_group()).is_zero()
+}
+
+/// Returns `a^(2^252 - 3)`, i.e. `a^((p - 5) / 8)`, with the addition chain
+/// of the ref10 implementation: 251 squarings and 11 multiplications.
+fn pow_p58(a: &Fq) -> Fq {
+    let square_n = |mut t: Fq, n: usize| {
+        for _ in 0..n {
+            t.square_in_place();
+        }
+        t
+    };
+    let z2 = a.square();
+    let z9 = square_n(z2, 2) * a;
+    let z11 = z9 * z2;
+    let z_5_0 = z11.square() * z9;
+    let z_10_0 = square_n(z_5_0, 5) * z_5_0;
+    let z_20_0 = square_n(z_10_0, 10) * z_10_0;
+    let z_40_0 = square_n(z_20_0, 20) * z_20_0;
+    let z_50_0 = square_n(z_40_0, 10) * z_10_0;
+    let z_100_0 = square_n(z_50_0, 50) * z_50_0;
+    let z_200_0 = square_n(z_100_0, 100) * z_100_0;
+    let z_250_0 = square_n(z_200_0, 50) * z_50_0;
+    square_n(z_250_0, 2) * a
+}
+
+/// Returns a square root of `u / v` for a non-zero `v`, if any, with a single
+/// exponentiation.
+fn sqrt_ratio(u: &Fq, v: &Fq) -> Option<Fq> {
+    let v3 = v.square() * v;
+    let r = *u * v3 * pow_p58(&(*u * v3.square() * v));
+    let check = *v * r.square();
+    if check == *u {
+        Some(r)
+    } else if check == -*u {
+        Some(r * SQRT_MINUS_ONE)
+    } else {
+        None
+    }
+}
+
+/// Returns `a^((p - 1) / 4)`, the quartic residue symbol of `a`.
+fn quartic_character(a: &Fq) -> Fq {
+    pow_p58(a).square() * a
+}
+
+/// Returns whether `p` lies in the prime-order subgroup, i.e. whether it has no
+/// component of small order.
+///
+/// As E(Fq) is cyclic of order 8r, this is whether `p` is in [8]E(Fq). With
+/// the point halving of <https://eprint.iacr.org/2022/1164>, `p` is halved
+/// once, which is possible exactly when `p` is in [2]E(Fq), and the half `q`
+/// is checked to be in [4]E(Fq) with the reduced Tate pairing of order 4 with
+/// a point of order 4, i.e. with a quartic residue symbol. This costs three or
+/// four exponentiations in Fq, about a quarter of a multiplication by r.
+pub fn is_torsion_free(p: &EdwardsAffine) -> bool {
+    let (x, y) = (p.x, p.y);
+    // The identity and the point of order 2 have x = 0, and the points of
+    // order 4 have y = 0.
+    if x.is_zero() {
+        return y.is_one();
+    }
+    if y.is_zero() {
+        return false;
+    }
+    let d = EdwardsConfig::COEFF_D;
+
+    // X = x_q^2 is a root of d (1 - y) X^2 - 2 (1 + d y) X - (1 - y), whose
+    // discriminant is a square exactly when p is in [2]E(Fq). The roots are
+    // (x (1 + d y) +- s) / (d x (1 - y)), where s^2 = (1 + d) (y^2 - 1), and
+    // exactly one of them is a square since their product -1/d is not.
+    let Some(s) = sqrt_ratio(&((Fq::ONE + d) * (y.square() - Fq::ONE)), &Fq::ONE) else {
+        return false;
+    };
+    let den = d * x * (Fq::ONE - y);
+    let (num, x_q) = [x * (Fq::ONE + d * y) + s, x * (Fq::ONE + d * y) - s]
+        .into_iter()
+        .find_map(|num| sqrt_ratio(&num, &den).map(|x_q| (num, x_q)))
+        .expect("one of the roots is a square");
+
+    // y_q = a / b follows from x = 2 x_q y_q / (y_q^2 - x_q^2).
+    let a = x * (den.square() + d * num.square());
+    let b = x_q.double() * den * (den - d * num);
+
+    // With the point T = (sqrt(-1), 0) of order 4, the Miller function of the
+    // pairing is, up to 4th powers and a constant that is a 4th power,
+    // (1 + y) (1 - y)^3 x^2 (x - sqrt(-1))^2, here evaluated at q.
+    let f = (b + a) * (b - a).square() * (b - a) * x_q.square() * (x_q - SQRT_MINUS_ONE).square();
+    quartic_character(&f).is_one()
+}
+
+/// The 8 points of small order, where `SMALL_ORDER_POINTS[i]` = [i]T for the
+/// point T of order 8 with compressed encoding
+/// c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a.
+///
+/// These are the points whose encodings RFC 8032 verifiers commonly blocklist.
+pub const SMALL_ORDER_POINTS: [EdwardsAffine; 8] = [
+    EdwardsAffine::new_unchecked(Fq::ZERO, Fq::ONE),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X1, SMALL_ORDER_Y1),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X2, Fq::ZERO),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X1, SMALL_ORDER_Y3),
+    EdwardsAffine::new_unchecked(Fq::ZERO, MontFp!("-1")),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X5, SMALL_ORDER_Y3),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X6, Fq::ZERO),
+    EdwardsAffine::new_unchecked(SMALL_ORDER_X5, SMALL_ORDER_Y1),
+];
+
+/// SMALL_ORDER_X1 =
+/// 14399317868200118260347934320527232580618823971194345261214217575416788799818
+const SMALL_ORDER_X1: Fq =
+    MontFp!("14399317868200118260347934320527232580618823971194345261214217575416788799818");
+
+/// SMALL_ORDER_Y1 =
+/// 55188659117513257062467267217118295137698188065244968500265048394206261417927
+const SMALL_ORDER_Y1: Fq =
+    MontFp!("55188659117513257062467267217118295137698188065244968500265048394206261417927");
+
+/// SMALL_ORDER_X2 = SQRT_MINUS_ONE
+const SMALL_ORDER_X2: Fq = SQRT_MINUS_ONE;
+
+/// SQRT_MINUS_ONE = sqrt(-1) =
+/// 38214883241950591754978413199355411911188925816896391856984770930832735035197
+const SQRT_MINUS_ONE: Fq =
+    MontFp!("38214883241950591754978413199355411911188925816896391856984770930832735035197");
+
+/// SMALL_ORDER_Y3 = -SMALL_ORDER_Y1 =
+/// 2707385501144840649318225287225658788936804267575313519463743609750303402022
+const SMALL_ORDER_Y3: Fq =
+    MontFp!("2707385501144840649318225287225658788936804267575313519463743609750303402022");
+
+/// SMALL_ORDER_X5 = -SMALL_ORDER_X1 =
+/// 43496726750457979451437558183816721346016168361625936758514574428539776020131
+const SMALL_ORDER_X5: Fq =
+    MontFp!("43496726750457979451437558183816721346016168361625936758514574428539776020131");
+
+/// SMALL_ORDER_X6 = -SMALL_ORDER_X2 =
+/// 19681161376707505956807079304988542015446066515923890162744021073123829784752
+const SMALL_ORDER_X6: Fq =
+    MontFp!("19681161376707505956807079304988542015446066515923890162744021073123829784752");
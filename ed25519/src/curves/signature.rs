@@ -0,0 +1,275 @@
+//! Ed25519 signatures of [RFC 8032, section 5.1].
+//!
+//! Keys are derived from a 32-byte seed with SHA-512, and signing is
+//! deterministic. Points are decoded with the rules of an
+//! [`encoding::Mode`], which is chosen when parsing the public key and also
+//! applies to the commitment `R` of the signatures it verifies. The
+//! verification equation is either the cofactorless `[S]B = R + [k]A` or the
+//! cofactored `[8][S]B = [8]R + [8][k]A`, see [`Equation`]; the [ZIP-215]
+//! rules correspond to [`encoding::Mode::Zip215`] with
+//! [`Equation::Cofactored`].
+//!
+//! Small-order public keys are not rejected; callers that need to can check
+//! them with [`crate::is_small_order`].
+//!
+//! [RFC 8032, section 5.1]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+//! [ZIP-215]: https://zips.z.cash/zip-0215
+
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::{fmt, rand::Rng, vec::Vec};
+use sha2::{Digest, Sha512};
+
+use crate::{
+    encoding::{self, Mode},
+    is_small_order, mul_by_cofactor, EdwardsAffine, EdwardsProjective, Fr,
+};
+
+/// Size in bytes of a seed, from which a signing key is derived.
+pub const SEED_SIZE: usize = 32;
+/// Size in bytes of an encoded public key.
+pub const PUBLIC_KEY_SIZE: usize = encoding::ENCODED_SIZE;
+/// Size in bytes of an encoded signature.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// Errors returned when decoding keys and signatures or verifying signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The public key or the commitment `R` is not a valid point encoding.
+    InvalidPoint(encoding::Error),
+    /// The scalar `S` is not smaller than the group order.
+    ScalarOutOfRange,
+    /// The verification equation does not hold.
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPoint(e) => write!(f, "invalid point: {}", e),
+            Error::ScalarOutOfRange => write!(f, "scalar exceeds group order"),
+            Error::InvalidSignature => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+impl From<encoding::Error> for Error {
+    fn from(e: encoding::Error) -> Self {
+        Error::InvalidPoint(e)
+    }
+}
+
+/// The verification equation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Equation {
+    /// `[S]B = R + [k]A`, which rejects some signatures involving points with
+    /// a small-order component.
+    Cofactorless,
+    /// `[8][S]B = [8]R + [8][k]A`, which ignores small-order components and
+    /// agrees with batch verification.
+    Cofactored,
+}
+
+/// Computes SHA-512 of the concatenation of `parts`, reduced modulo the
+/// group order.
+fn hash_to_scalar(parts: &[&[u8]]) -> Fr {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+/// An Ed25519 signing key, derived from a seed.
+#[derive(Clone)]
+pub struct SigningKey {
+    seed: [u8; SEED_SIZE],
+    scalar: Fr,
+    prefix: [u8; 32],
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    /// Derives the signing key of `seed` as in RFC 8032, section 5.1.5: the
+    /// first half of SHA-512(seed) is clamped into the secret scalar, and the
+    /// second half is the prefix used to derive nonces.
+    pub fn from_seed(seed: &[u8; SEED_SIZE]) -> Self {
+        let h = Sha512::digest(seed);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&h[..32]);
+        s[0] &= 248;
+        s[31] &= 127;
+        s[31] |= 64;
+        let scalar = Fr::from_le_bytes_mod_order(&s);
+
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+
+        let point = (EdwardsAffine::generator() * scalar).into_affine();
+        let verifying_key = VerifyingKey {
+            point,
+            bytes: encoding::encode_point(&point),
+            mode: Mode::Strict,
+        };
+        Self {
+            seed: *seed,
+            scalar,
+            prefix,
+            verifying_key,
+        }
+    }
+
+    /// Returns the seed this key was derived from.
+    pub fn seed(&self) -> &[u8; SEED_SIZE] {
+        &self.seed
+    }
+
+    /// Returns the public key, which decodes points in [`Mode::Strict`].
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `message` as in RFC 8032, section 5.1.6.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let r = hash_to_scalar(&[&self.prefix, message]);
+        let big_r = encoding::encode_point(&(EdwardsAffine::generator() * r).into_affine());
+        let k = hash_to_scalar(&[&big_r, &self.verifying_key.bytes, message]);
+        Signature {
+            r: big_r,
+            s: r + k * self.scalar,
+        }
+    }
+}
+
+/// An Ed25519 public key, together with the rules used to decode it and the
+/// commitments of the signatures it verifies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    point: EdwardsAffine,
+    bytes: [u8; PUBLIC_KEY_SIZE],
+    mode: Mode,
+}
+
+impl VerifyingKey {
+    /// Decodes a public key with the rules of `mode`. The encoding is kept
+    /// as given, since it is hashed during verification.
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_SIZE], mode: Mode) -> Result<Self, Error> {
+        Ok(Self {
+            point: encoding::decode_point(bytes, mode)?,
+            bytes: *bytes,
+            mode,
+        })
+    }
+
+    /// Returns the encoding of the public key.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
+        self.bytes
+    }
+
+    /// Returns the public key as a point.
+    pub fn point(&self) -> &EdwardsAffine {
+        &self.point
+    }
+
+    /// Returns the decoding rules of the public key.
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Decodes `R` and computes `k = SHA-512(R || A || message)`.
+    fn challenge(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(EdwardsAffine, Fr), Error> {
+        let r = encoding::decode_point(&signature.r, self.mode)?;
+        let k = hash_to_scalar(&[&signature.r, &self.bytes, message]);
+        Ok((r, k))
+    }
+
+    /// Verifies `signature` on `message` as in RFC 8032, section 5.1.7,
+    /// checking the given verification equation.
+    pub fn verify(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+        equation: Equation,
+    ) -> Result<(), Error> {
+        let (r, k) = self.challenge(message, signature)?;
+        let diff = EdwardsAffine::generator() * signature.s - r - self.point * k;
+        let valid = match equation {
+            Equation::Cofactorless => diff.is_zero(),
+            Equation::Cofactored => is_small_order(&diff.into_affine()),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
+    }
+}
+
+/// An Ed25519 signature `(R, S)`. `R` is kept encoded, since it is hashed
+/// during verification and decoded with the rules of the public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    r: [u8; encoding::ENCODED_SIZE],
+    s: Fr,
+}
+
+impl Signature {
+    /// Decodes a signature `R || S`, rejecting values of `S` that are not
+    /// smaller than the group order.
+    pub fn from_bytes(bytes: &[u8; SIGNATURE_SIZE]) -> Result<Self, Error> {
+        let mut r = [0u8; encoding::ENCODED_SIZE];
+        r.copy_from_slice(&bytes[..32]);
+        let s = Fr::from_le_bytes_mod_order(&bytes[32..]);
+        if s.into_bigint().to_bytes_le() != bytes[32..] {
+            return Err(Error::ScalarOutOfRange);
+        }
+        Ok(Self { r, s })
+    }
+
+    /// Encodes the signature as `R || S`.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut bytes = [0u8; SIGNATURE_SIZE];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&self.s.into_bigint().to_bytes_le());
+        bytes
+    }
+}
+
+/// Verifies a batch of signatures at once with a single multi-scalar
+/// multiplication, by checking the cofactored equation on a random linear
+/// combination of the individual equations.
+///
+/// The batch is accepted if and only if, except with negligible probability,
+/// each signature is accepted by [`VerifyingKey::verify`] with
+/// [`Equation::Cofactored`]. The cofactorless equation cannot be checked in a
+/// batch, since the random coefficients may cancel small-order components.
+pub fn verify_batch<R: Rng>(
+    items: &[(&VerifyingKey, &[u8], &Signature)],
+    rng: &mut R,
+) -> Result<(), Error> {
+    let mut bases = Vec::with_capacity(2 * items.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut s = Fr::zero();
+    for (key, message, signature) in items {
+        let (r, k) = key.challenge(message, signature)?;
+        let z = Fr::from(rng.gen::<u128>());
+        s += z * signature.s;
+        bases.push(r);
+        scalars.push(z);
+        bases.push(key.point);
+        scalars.push(z * k);
+    }
+    bases.push(EdwardsAffine::generator());
+    scalars.push(-s);
+
+    let sum = EdwardsProjective::msm_unchecked(&bases, &scalars);
+    if mul_by_cofactor(&sum).is_zero() {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
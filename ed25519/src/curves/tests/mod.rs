@@ -0,0 +1,356 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{AdditiveGroup, Field, UniformRand, Zero};
+use ark_std::{rand::Rng, test_rng};
+use sha2::Sha512;
+
+extern crate std;
+use std::{format, fs::File, io::BufReader, vec, vec::Vec};
+
+test_group!(te; EdwardsProjective; te);
+
+fn read_point(x: Fq, y: Fq) -> EdwardsAffine {
+    let p = EdwardsAffine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map>(
+        "./src/curves/tests/edwards25519_XMD-SHA-512_ELL2_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map>(
+        "./src/curves/tests/edwards25519_XMD-SHA-512_ELL2_NU_.json",
+        read_point,
+    );
+}
+
+/// Returns a uniformly random point of E(Fq), which usually has a small-order
+/// component.
+fn random_point<R: Rng>(rng: &mut R) -> EdwardsAffine {
+    loop {
+        if let Some(p) = EdwardsAffine::get_point_from_y_unchecked(Fq::rand(rng), rng.gen()) {
+            return p;
+        }
+    }
+}
+
+#[test]
+fn test_small_order_points() {
+    let t = SMALL_ORDER_POINTS[1];
+    let mut expected = EdwardsProjective::zero();
+    for (i, p) in SMALL_ORDER_POINTS.iter().enumerate() {
+        assert!(p.is_on_curve());
+        assert_eq!(*p, expected.into_affine());
+        assert!(is_small_order(p));
+        assert_eq!(is_torsion_free(p), i == 0);
+        assert!(mul_by_cofactor(&p.into_group()).is_zero());
+        expected += t;
+    }
+    assert!(expected.is_zero());
+
+    // T has order 8.
+    assert!(!t.into_group().double().double().is_zero());
+
+    // The small-order component of a random point is one of the table entries.
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        let p = random_point(&mut rng);
+        let t = p.mul_bigint(Fr::characteristic()).into_affine();
+        assert!(SMALL_ORDER_POINTS.contains(&t));
+        assert_eq!(is_torsion_free(&p), t.is_zero());
+        assert_eq!(
+            is_torsion_free(&p),
+            p.is_in_correct_subgroup_assuming_on_curve()
+        );
+        assert_eq!(is_small_order(&p), SMALL_ORDER_POINTS.contains(&p));
+    }
+}
+
+#[test]
+fn test_mixed_points() {
+    let mut rng = test_rng();
+    for t in SMALL_ORDER_POINTS {
+        let g = EdwardsProjective::rand(&mut rng);
+        let p = (g + t).into_affine();
+        assert!(p.is_on_curve());
+        assert!(!is_small_order(&p));
+        assert_eq!(is_torsion_free(&p), t.is_zero());
+        assert_eq!(mul_by_cofactor(&p.into_group()), mul_by_cofactor(&g));
+        assert_eq!(
+            mul_by_cofactor(&p.into_group()),
+            p.mul_by_cofactor_to_group()
+        );
+        assert!(is_torsion_free(
+            &mul_by_cofactor(&p.into_group()).into_affine()
+        ));
+    }
+    assert!(is_torsion_free(&EdwardsAffine::generator()));
+    assert!(!is_small_order(&EdwardsAffine::generator()));
+}
+
+/// Returns whether [r]P is the identity, the reference for `is_torsion_free`.
+fn is_torsion_free_reference(p: &EdwardsAffine) -> bool {
+    p.mul_bigint(Fr::characteristic()).is_zero()
+}
+
+#[test]
+fn test_torsion_free_matches_reference() {
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        let g = EdwardsProjective::rand(&mut rng);
+        for t in SMALL_ORDER_POINTS {
+            for p in [g + t, -g + t, g.double() + t] {
+                let p = p.into_affine();
+                assert_eq!(is_torsion_free(&p), is_torsion_free_reference(&p));
+                assert_eq!(is_torsion_free(&p), t.is_zero());
+            }
+        }
+        let p = random_point(&mut rng);
+        assert_eq!(is_torsion_free(&p), is_torsion_free_reference(&p));
+    }
+    for t in SMALL_ORDER_POINTS {
+        assert_eq!(is_torsion_free(&t), is_torsion_free_reference(&t));
+    }
+}
+
+fn read_encoding(value: &serde_json::Value) -> [u8; encoding::ENCODED_SIZE] {
+    hex::decode(value.as_str().unwrap())
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_rfc8032_point_encoding() {
+    let file = File::open("./src/curves/tests/rfc8032_point_encoding.json").unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    for v in data.as_array().unwrap() {
+        let bytes = read_encoding(&v["encoding"]);
+        for (mode, key) in [
+            (encoding::Mode::Strict, "strict"),
+            (encoding::Mode::Zip215, "zip215"),
+        ] {
+            let result = encoding::decode_point(&bytes, mode);
+            if v[key].is_null() {
+                assert!(result.is_err(), "{}: {}", key, v["comment"]);
+            } else {
+                let p = result.unwrap();
+                assert!(p.is_on_curve());
+                assert_eq!(
+                    encoding::encode_point(&p),
+                    read_encoding(&v[key]),
+                    "{}: {}",
+                    key,
+                    v["comment"]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rfc8032_point_encoding_errors() {
+    use encoding::{decode_point, Error, Mode};
+
+    // The generator is encoded as 4/5 with a positive x.
+    let mut g = [0x66; encoding::ENCODED_SIZE];
+    g[0] = 0x58;
+    assert_eq!(encoding::encode_point(&EdwardsAffine::generator()), g);
+    assert_eq!(
+        decode_point(&g, Mode::Strict),
+        Ok(EdwardsAffine::generator())
+    );
+
+    // y = q, which reduces to the point (sqrt(-1), 0) of order 4.
+    let mut q = [0xff; encoding::ENCODED_SIZE];
+    q[0] = 0xed;
+    q[31] = 0x7f;
+    assert_eq!(decode_point(&q, Mode::Strict), Err(Error::NonCanonicalY));
+    assert_eq!(decode_point(&q, Mode::Zip215).map(|p| p.y), Ok(Fq::ZERO));
+
+    // The identity with the sign bit set.
+    let mut id = [0u8; encoding::ENCODED_SIZE];
+    id[0] = 1;
+    id[31] = 0x80;
+    assert_eq!(
+        decode_point(&id, Mode::Strict),
+        Err(Error::NonCanonicalSign)
+    );
+    assert_eq!(decode_point(&id, Mode::Zip215), Ok(EdwardsAffine::zero()));
+
+    // y = 2 is not the y-coordinate of any point.
+    let mut two = [0u8; encoding::ENCODED_SIZE];
+    two[0] = 2;
+    assert_eq!(decode_point(&two, Mode::Zip215), Err(Error::NotOnCurve));
+}
+
+#[test]
+fn test_rfc8032_point_encoding_roundtrip() {
+    let mut rng = test_rng();
+    let points = (0..20)
+        .map(|_| random_point(&mut rng))
+        .chain(SMALL_ORDER_POINTS);
+    for p in points {
+        let bytes = encoding::encode_point(&p);
+        assert_eq!(
+            encoding::decode_point(&bytes, encoding::Mode::Strict),
+            Ok(p)
+        );
+        assert_eq!(
+            encoding::decode_point(&bytes, encoding::Mode::Zip215),
+            Ok(p)
+        );
+    }
+}
+
+/// The test vectors of RFC 8032, section 7.1, as vendored by the
+/// `cosmwasm-crypto` crate.
+#[test]
+fn test_ed25519_rfc8032() {
+    use signature::{Equation, Signature, SigningKey};
+
+    let file = File::open("./src/curves/tests/rfc8032_ed25519.json").unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let vectors = data.as_array().unwrap().iter().map(|v| {
+        (
+            v["privkey"].as_str().unwrap(),
+            v["pubkey"].as_str().unwrap(),
+            v["message"].as_str().unwrap(),
+            v["signature"].as_str().unwrap(),
+        )
+    });
+    for (seed, public, message, sig) in vectors {
+        let key = SigningKey::from_seed(&hex::decode(seed).unwrap().try_into().unwrap());
+        let message = hex::decode(message).unwrap();
+        let sig: [u8; 64] = hex::decode(sig).unwrap().try_into().unwrap();
+        assert_eq!(
+            key.verifying_key().to_bytes().to_vec(),
+            hex::decode(public).unwrap()
+        );
+
+        let signature = key.sign(&message);
+        assert_eq!(signature.to_bytes(), sig);
+        assert_eq!(Signature::from_bytes(&sig), Ok(signature));
+        for equation in [Equation::Cofactorless, Equation::Cofactored] {
+            assert!(key
+                .verifying_key()
+                .verify(&message, &signature, equation)
+                .is_ok());
+            assert_eq!(
+                key.verifying_key().verify(&[1], &signature, equation),
+                Err(signature::Error::InvalidSignature)
+            );
+        }
+    }
+}
+
+/// The 12 cases of <https://github.com/novifinancial/ed25519-speccheck>, from
+/// "Taming the many EdDSAs", as vendored by the `soroban-env-host` crate. The
+/// ZIP-215 cofactored results are those of `ed25519-consensus`; the others
+/// were computed with the reference implementation of RFC 8032, section 6.
+#[test]
+fn test_ed25519_speccheck() {
+    use signature::{Equation, Signature, VerifyingKey};
+
+    let file = File::open("./src/curves/tests/speccheck_cases.json").unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+
+    for v in data.as_array().unwrap() {
+        let public: [u8; 32] = read_encoding(&v["pub_key"]);
+        let message = hex::decode(v["message"].as_str().unwrap()).unwrap();
+        let sig: [u8; 64] = hex::decode(v["signature"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        for (mode, mode_name) in [
+            (encoding::Mode::Strict, "strict"),
+            (encoding::Mode::Zip215, "zip215"),
+        ] {
+            for (equation, equation_name) in [
+                (Equation::Cofactorless, "cofactorless"),
+                (Equation::Cofactored, "cofactored"),
+            ] {
+                let result = VerifyingKey::from_bytes(&public, mode)
+                    .and_then(|key| key.verify(&message, &Signature::from_bytes(&sig)?, equation));
+                let key = format!("{}_{}", mode_name, equation_name);
+                assert_eq!(
+                    result.is_ok(),
+                    v[&key].as_bool().unwrap(),
+                    "{}: {}",
+                    key,
+                    v["comment"]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ed25519_verify_batch() {
+    use signature::{verify_batch, Equation, Signature, SigningKey, VerifyingKey};
+
+    let mut rng = test_rng();
+    let keys = (0..8)
+        .map(|_| SigningKey::from_seed(&rng.gen()))
+        .collect::<Vec<_>>();
+    let messages = (0..8).map(|i| vec![i as u8; i]).collect::<Vec<_>>();
+    let signatures = keys
+        .iter()
+        .zip(&messages)
+        .map(|(key, message)| key.sign(message))
+        .collect::<Vec<_>>();
+    let items = keys
+        .iter()
+        .zip(&messages)
+        .zip(&signatures)
+        .map(|((key, message), sig)| (key.verifying_key(), message.as_slice(), sig))
+        .collect::<Vec<_>>();
+    assert!(verify_batch(&items, &mut rng).is_ok());
+    assert!(verify_batch(&[], &mut rng).is_ok());
+
+    // A signature on the wrong message invalidates the batch.
+    let mut bad = items.clone();
+    bad[3].1 = &messages[4];
+    assert_eq!(
+        verify_batch(&bad, &mut rng),
+        Err(signature::Error::InvalidSignature)
+    );
+
+    // The batch agrees with the cofactored equation on the speccheck cases.
+    let file = File::open("./src/curves/tests/speccheck_cases.json").unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    for v in data.as_array().unwrap() {
+        let public: [u8; 32] = read_encoding(&v["pub_key"]);
+        let message = hex::decode(v["message"].as_str().unwrap()).unwrap();
+        let sig: [u8; 64] = hex::decode(v["signature"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let (Ok(key), Ok(sig)) = (
+            VerifyingKey::from_bytes(&public, encoding::Mode::Zip215),
+            Signature::from_bytes(&sig),
+        ) else {
+            continue;
+        };
+        let single = key.verify(&message, &sig, Equation::Cofactored);
+        let mut batch = items.clone();
+        batch.push((&key, &message, &sig));
+        assert_eq!(
+            verify_batch(&batch, &mut rng).is_ok(),
+            single.is_ok(),
+            "{}",
+            v["comment"]
+        );
+    }
+}
@@ -1,8 +1,14 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use sha2::Sha512;
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -12,6 +18,18 @@ mod tests;
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
+/// Hashes to the curve with the `ed_on_bls12_377_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "ed_on_bls12_377_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "ed_on_bls12_377_XMD:SHA-512_ELL2_NU_";
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsConfig;
 
@@ -62,6 +80,22 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 11
+    const Z: Fq = MontFp!("11");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 6333346312071277818186618704086159898531924501365547870951425091938057500061
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("6333346312071277818186618704086159898531924501365547870951425091938057500061");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B = 1510
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("1510");
+}
+
 /// GENERATOR_X =
 /// 4497879464030519973909970603271755437257548612157028181994697785683032656389,
 pub const GENERATOR_X: Fq =
@@ -1,9 +1,16 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     short_weierstrass::{self, SWCurveConfig},
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+    AffineRepr,
 };
-use ark_ff::MontFp;
+use ark_ff::{Field, MontFp, One, Zero};
+use sha2::Sha512;
 
 use crate::{Fq, Fr};
 
@@ -12,6 +19,18 @@ mod tests;
 
 pub type EdwardsAffine = Affine<JubjubConfig>;
 pub type EdwardsProjective = Projective<JubjubConfig>;
+
+/// Hashes to the curve with the `jubjub_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "jubjub_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "jubjub_XMD:SHA-512_ELL2_NU_";
 pub type SWAffine = short_weierstrass::Affine<JubjubConfig>;
 pub type SWProjective = short_weierstrass::Projective<JubjubConfig>;
 
@@ -84,6 +103,20 @@ impl TECurveConfig for JubjubConfig {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         -elem
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &EdwardsAffine) -> bool {
+        // The identity and the point of order 2 are the only points with x = 0.
+        if p.x.is_zero() {
+            return p.y.is_one();
+        }
+        // Maps p to the Montgomery form:
+        // (u, v) = ((1 + y) / (1 - y), (1 + y) / ((1 - y) * x)) = (U / W^2, V / W^3).
+        let w = (Fq::ONE - p.y) * p.x;
+        let u = (Fq::ONE + p.y) * p.x * w;
+        let v = u * (Fq::ONE - p.y);
+        is_in_prime_order_subgroup(u, v, w)
+    }
 }
 
 impl MontCurveConfig for JubjubConfig {
@@ -96,6 +129,24 @@ impl MontCurveConfig for JubjubConfig {
     type TECurveConfig = JubjubConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for JubjubConfig {
+    /// Z = 5
+    const Z: Fq = MontFp!("5");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 19676371192118968049803995723067767322369844622612809987311387700305851196142
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("19676371192118968049803995723067767322369844622612809987311387700305851196142");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B =
+    /// 9628519018340474679875156334893438995974717701127060143092098445975442038616
+    const COEFF_A_OVER_COEFF_B: Fq =
+        MontFp!("9628519018340474679875156334893438995974717701127060143092098445975442038616");
+}
+
 /// GENERATOR_X =
 /// 8076246640662884909881801758704306714034609987455869804520522091855516602923
 pub const GENERATOR_X: Fq =
@@ -117,6 +168,82 @@ impl SWCurveConfig for JubjubConfig {
 
     /// generators
     const GENERATOR: SWAffine = SWAffine::new_unchecked(SW_GENERATOR_X, SW_GENERATOR_Y);
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &SWAffine) -> bool {
+        if p.is_zero() {
+            return true;
+        }
+        // Maps p to the Montgomery form: (u, v) = (B * x - A / 3, B * y).
+        let b = <Self as MontCurveConfig>::COEFF_B;
+        is_in_prime_order_subgroup(b * p.x - MONT_A_OVER_THREE, b * p.y, Fq::ONE)
+    }
+}
+
+/// MONT_A_OVER_THREE = A / 3 = 13654, where A is the Montgomery COEFF_A
+const MONT_A_OVER_THREE: Fq = MontFp!("13654");
+
+/// TATE_L1 and TATE_M1 define the tangent v = TATE_L1 * u + TATE_M1 to the
+/// Montgomery form at a point T of order 8, used in `is_in_prime_order_subgroup`.
+///
+/// TATE_L1 = 28151743229145285823507369548784938320847655557252073286294803267707087926919
+const TATE_L1: Fq =
+    MontFp!("28151743229145285823507369548784938320847655557252073286294803267707087926919");
+
+/// TATE_M1 = 24284131945980904652475226133327375198066627412587821758038602963466131294586
+const TATE_M1: Fq =
+    MontFp!("24284131945980904652475226133327375198066627412587821758038602963466131294586");
+
+/// TATE_L2 = sqrt(-1) = 3465144826073652318776269530687742778270252468765361963008
+const TATE_L2: Fq = MontFp!("3465144826073652318776269530687742778270252468765361963008");
+
+/// ONE_OVER_MONT_B = 1 / B =
+/// 21403678078392857899786292086646263420857917399700288839755780126981569572948
+const ONE_OVER_MONT_B: Fq =
+    MontFp!("21403678078392857899786292086646263420857917399700288839755780126981569572948");
+
+/// (q - 1) / 8 =
+/// 6554484396890773809930967563523245729711319062565954727825457337492322648064
+const Q_MINUS_ONE_DIV_EIGHT: &[u64] = &[
+    0xdfffffffe0000000,
+    0xaa77b4805fffcb7f,
+    0x06673b0101343b00,
+    0x0e7db4ea6533afa9,
+];
+
+/// Returns whether the point (U / W^2, V / W^3) of the Montgomery form
+/// B * v^2 = u^3 + A * u^2 + u, with W != 0, lies in the prime-order subgroup.
+///
+/// Since E(Fq) = G x Z/8 and 8 divides q - 1, the reduced Tate pairing with a
+/// point T of order 8, P -> f(P)^((q - 1) / 8) where f has divisor
+/// 8(T) - 8(O) and is normalized at O, is a character of E(Fq) whose kernel
+/// is 8E(Fq) = G. See <https://eprint.iacr.org/2022/037>. This costs one
+/// exponentiation instead of a 252-bit scalar multiplication.
+///
+/// Miller's algorithm gives f = l_T^4 * l_2T^2 / (v_2T^4 * v_4T * B^17), where
+/// * l_T = v - TATE_L1 * u - TATE_M1 is the tangent at T,
+/// * l_2T = v - TATE_L2 * u is the tangent at 2T = (1, TATE_L2),
+/// * v_2T = u - 1 and v_4T = u are the vertical lines at 2T and 4T = (0, 0),
+///
+/// and B^17 normalizes the product of the 17 lines. Eighth powers vanish in
+/// the final exponentiation, so f can be replaced by
+/// l_T^4 * l_2T^2 * v_2T^4 * v_4T^7 / B. As this has weight 40 in (U, V, W),
+/// the powers of W are also eighth powers.
+///
+/// The lines only vanish at points of small order, which are then rejected.
+fn is_in_prime_order_subgroup(u: Fq, v: Fq, w: Fq) -> bool {
+    let w2 = w.square();
+    let w3 = w2 * w;
+    let uw = u * w;
+    let l_t = v - TATE_L1 * uw - TATE_M1 * w3;
+    let l_2t = v - TATE_L2 * uw;
+    let v_2t = u - w2;
+
+    let mut f = (l_t * v_2t).square() * l_2t;
+    f.square_in_place();
+    let u2 = u.square();
+    f *= u2.square() * u2 * u * ONE_OVER_MONT_B;
+    f.pow(Q_MINUS_ONE_DIV_EIGHT).is_one()
 }
 
 /// x coordinate for SW curve generator
@@ -1,5 +0,0 @@
-use crate::*;
-use ark_algebra_test_templates::*;
-
-test_group!(sw; SWProjective; sw);
-test_group!(te; EdwardsProjective; te);
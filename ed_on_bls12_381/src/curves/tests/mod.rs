@@ -0,0 +1,166 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::twisted_edwards::MontCurveConfig;
+use ark_ec::{hashing::curve_maps::elligator2::Elligator2Map, AffineRepr, CurveGroup};
+use ark_ff::{AdditiveGroup, Field, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{rand::Rng, test_rng};
+use sha2::Sha512;
+
+extern crate std;
+use std::vec::Vec;
+
+test_group!(sw; SWProjective; sw);
+test_group!(te; EdwardsProjective; te);
+
+fn read_point(x: Fq, y: Fq) -> EdwardsAffine {
+    let p = EdwardsAffine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/jubjub_XMD-SHA-512_ELL2_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/jubjub_XMD-SHA-512_ELL2_NU_.json",
+        read_point,
+    );
+}
+
+/// Returns a uniformly random point of E(Fq), which usually has a small-order
+/// component.
+fn random_te_point<R: Rng>(rng: &mut R) -> EdwardsAffine {
+    loop {
+        if let Some(p) = EdwardsAffine::get_point_from_y_unchecked(Fq::rand(rng), rng.gen()) {
+            return p;
+        }
+    }
+}
+
+/// Returns a point of order 8, which generates the small-order points.
+fn te_point_of_order_eight() -> EdwardsAffine {
+    let mut rng = test_rng();
+    loop {
+        let t = random_te_point(&mut rng).mul_bigint(Fr::characteristic());
+        if !t.double().double().is_zero() {
+            return t.into_affine();
+        }
+    }
+}
+
+/// Tests the subgroup check against multiplication by r.
+fn test_te_subgroup_check(p: &EdwardsAffine) {
+    assert!(p.is_on_curve());
+    let in_subgroup = p.mul_bigint(Fr::characteristic()).is_zero();
+    assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+
+    let q = te_to_sw(p);
+    assert!(q.is_on_curve());
+    assert_eq!(q.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+}
+
+/// The birational map from twisted Edwards to short Weierstrass form, through
+/// the Montgomery form. The points with x = 0 are mapped by continuity.
+fn te_to_sw(p: &EdwardsAffine) -> SWAffine {
+    if p.is_zero() {
+        return SWAffine::zero();
+    }
+    let (a, b) = (
+        <JubjubConfig as MontCurveConfig>::COEFF_A,
+        <JubjubConfig as MontCurveConfig>::COEFF_B,
+    );
+    let b_inv = b.inverse().unwrap();
+    let a_over_three = a / Fq::from(3u64);
+    if p.x.is_zero() {
+        // The point of order 2 is (0, 0) in Montgomery form.
+        return SWAffine::new_unchecked(a_over_three * b_inv, Fq::zero());
+    }
+    let u = (Fq::ONE + p.y) / (Fq::ONE - p.y);
+    let v = u / p.x;
+    SWAffine::new_unchecked((u + a_over_three) * b_inv, v * b_inv)
+}
+
+#[test]
+fn test_subgroup_membership() {
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        test_te_subgroup_check(&EdwardsAffine::rand(&mut rng));
+        test_te_subgroup_check(&random_te_point(&mut rng));
+    }
+    test_te_subgroup_check(&EdwardsAffine::generator());
+    test_te_subgroup_check(&EdwardsAffine::zero());
+    assert!(SWAffine::generator().is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_small_order_and_mixed_points() {
+    let mut rng = test_rng();
+    let t = te_point_of_order_eight();
+    let mut small_order = EdwardsProjective::zero();
+    for i in 0..8 {
+        // The i-th multiple of t, which has order 8 / gcd(i, 8).
+        test_te_subgroup_check(&small_order.into_affine());
+        assert_eq!(
+            small_order
+                .into_affine()
+                .is_in_correct_subgroup_assuming_on_curve(),
+            i == 0
+        );
+
+        let p = EdwardsProjective::rand(&mut rng);
+        let mixed = (p + small_order).into_affine();
+        test_te_subgroup_check(&mixed);
+        assert_eq!(mixed.is_in_correct_subgroup_assuming_on_curve(), i == 0);
+
+        small_order += t;
+    }
+    assert!(small_order.is_zero());
+}
+
+#[test]
+fn test_deserialization_rejects_points_outside_subgroup() {
+    let mut rng = test_rng();
+    let t = te_point_of_order_eight();
+    for _ in 0..10 {
+        let p = EdwardsProjective::rand(&mut rng);
+        let mixed = (p + t).into_affine();
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            p.into_affine()
+                .serialize_with_mode(&mut bytes, compress)
+                .unwrap();
+            let q = EdwardsAffine::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(q.unwrap(), p.into_affine());
+
+            for p in [mixed, t] {
+                let mut bytes = Vec::new();
+                p.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert!(
+                    EdwardsAffine::deserialize_with_mode(&bytes[..], compress, Validate::Yes)
+                        .is_err()
+                );
+                let q = EdwardsAffine::deserialize_with_mode(&bytes[..], compress, Validate::No);
+                assert_eq!(q.unwrap(), p);
+
+                let p = te_to_sw(&p);
+                let mut bytes = Vec::new();
+                p.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert!(
+                    SWAffine::deserialize_with_mode(&bytes[..], compress, Validate::Yes).is_err()
+                );
+                let q = SWAffine::deserialize_with_mode(&bytes[..], compress, Validate::No);
+                assert_eq!(q.unwrap(), p);
+            }
+        }
+    }
+}
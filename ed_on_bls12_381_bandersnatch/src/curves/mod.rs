@@ -1,9 +1,17 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
+    scalar_mul::{glv::GLVConfig, sw_double_and_add_affine, sw_double_and_add_projective},
     short_weierstrass::{self, SWCurveConfig},
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+    AffineRepr,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, BigInt, BitIteratorBE, Field, MontFp, One, PrimeField, Zero};
+use sha2::Sha512;
 
 use crate::{Fq, Fr};
 
@@ -13,6 +21,18 @@ mod tests;
 pub type EdwardsAffine = Affine<BandersnatchConfig>;
 pub type EdwardsProjective = Projective<BandersnatchConfig>;
 
+/// Hashes to the curve with the `bandersnatch_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "bandersnatch_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "bandersnatch_XMD:SHA-512_ELL2_NU_";
+
 pub type SWAffine = short_weierstrass::Affine<BandersnatchConfig>;
 pub type SWProjective = short_weierstrass::Projective<BandersnatchConfig>;
 
@@ -86,6 +106,37 @@ impl TECurveConfig for BandersnatchConfig {
     fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
         -(elem.double().double() + elem)
     }
+
+    #[inline]
+    fn mul_projective(base: &EdwardsProjective, scalar: &[u64]) -> EdwardsProjective {
+        if te_in_prime_order_subgroup(base) {
+            let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+            return glv_mul_te(base, s);
+        }
+        let mut res = EdwardsProjective::zero();
+        for b in BitIteratorBE::without_leading_zeros(scalar) {
+            res.double_in_place();
+            if b {
+                res += base;
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &EdwardsAffine) -> bool {
+        // The identity and the point of order 2 are the only affine points with
+        // x = 0. The other points of order 2 are at infinity.
+        if p.x.is_zero() {
+            return p.y.is_one();
+        }
+        // Checks that [N11]P = -psi([N12]P), see `subgroup_check_scalars`. As p
+        // is not of small order, neither is [N12]P, on which `te_endomorphism`
+        // is then well-defined.
+        let (n11, n12) = subgroup_check_scalars();
+        <Self as TECurveConfig>::mul_affine(p, n11)
+            == -te_endomorphism(&<Self as TECurveConfig>::mul_affine(p, n12))
+    }
 }
 
 impl MontCurveConfig for BandersnatchConfig {
@@ -100,6 +151,24 @@ impl MontCurveConfig for BandersnatchConfig {
     type TECurveConfig = BandersnatchConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for BandersnatchConfig {
+    /// Z = 5
+    const Z: Fq = MontFp!("5");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 35484827650731063748396669747216844996598387089274032563585525486049249153249
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("35484827650731063748396669747216844996598387089274032563585525486049249153249");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B =
+    /// 22511181562295907836254750456843438087744031914659733450388350895537307167857
+    const COEFF_A_OVER_COEFF_B: Fq =
+        MontFp!("22511181562295907836254750456843438087744031914659733450388350895537307167857");
+}
+
 // The TE form generator is generated following Zcash's fashion:
 //  "The generators of G1 and G2 are computed by finding the lexicographically
 //   smallest valid x-coordinate, and its lexicographically smallest
@@ -136,4 +205,196 @@ impl SWCurveConfig for BandersnatchConfig {
 
     /// generators
     const GENERATOR: SWAffine = SWAffine::new_unchecked(SW_GENERATOR_X, SW_GENERATOR_Y);
+
+    #[inline]
+    fn mul_projective(base: &SWProjective, scalar: &[u64]) -> SWProjective {
+        if sw_in_prime_order_subgroup(base) {
+            let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+            return GLVConfig::glv_mul_projective(*base, s);
+        }
+        sw_double_and_add_projective(base, scalar)
+    }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &SWAffine) -> bool {
+        // Checks that [N11]P = -psi([N12]P), see `subgroup_check_scalars`.
+        let (n11, n12) = subgroup_check_scalars();
+        sw_double_and_add_affine(p, n11) == -Self::endomorphism(&sw_double_and_add_affine(p, n12))
+    }
+}
+
+/// Returns the scalars (N11, N12) of the first vector of `SCALAR_DECOMP_COEFFS`.
+///
+/// They satisfy N11 + N12 * LAMBDA = 0 mod r and N11^2 + 2 * N12^2 = r, so the
+/// endomorphism [N11] + psi o [N12] kills the prime-order subgroup and has
+/// degree r: its kernel on E(Fq) is exactly the prime-order subgroup. This
+/// replaces a 253-bit scalar multiplication by two 127-bit ones.
+///
+/// The scalar multiplications must not go through GLV, which is only correct on
+/// the prime-order subgroup.
+fn subgroup_check_scalars() -> (&'static [u64], &'static [u64]) {
+    let [(_, n11), (_, n12), ..] = &BandersnatchConfig::SCALAR_DECOMP_COEFFS;
+    (n11.as_ref(), n12.as_ref())
+}
+
+// Bandersnatch has an endomorphism psi of degree 2 with psi^2 = -2, which
+// acts on the prime-order subgroup as multiplication by LAMBDA. See
+// [MSZ21, section 3](https://eprint.iacr.org/2021/1152).
+//
+// In short Weierstrass form, psi is the 2-isogeny with kernel (X0, 0)
+// followed by the isomorphism back to the curve:
+// psi(x, y) = (u^2 * (x + T / (x - X0)), u^3 * y * (1 - T / (x - X0)^2)),
+// where T = 3 * X0^2 + COEFF_A and u^2 = -1/2.
+impl GLVConfig for BandersnatchConfig {
+    /// ENDO_COEFFS = [X0, T, u^2, u^3]
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[
+        MontFp!("42460977304182762931716743824405123254375045638571669698531889431804823178961"),
+        MontFp!("35484827650731063748396669747216844996598387089274032563585525486049249153249"),
+        MontFp!("26217937587563095239723870254092982918845276250263818911301829349969290592256"),
+        MontFp!("8089994378958280414021351509578931277711957629993758335181991576135134307306"),
+    ];
+
+    /// LAMBDA = sqrt(-2) mod r
+    const LAMBDA: Self::ScalarField =
+        MontFp!("8913659658109529928382530854484400854125314752504019737736543920008458395397");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("113482231691339203864511368254957623327")),
+        (true, BigInt!("10741319382058138887739339959866629956")),
+        (false, BigInt!("21482638764116277775478679919733259912")),
+        (true, BigInt!("113482231691339203864511368254957623327")),
+    ];
+
+    fn endomorphism(p: &SWProjective) -> SWProjective {
+        // In Jacobian coordinates, with S = X - X0 * Z^2:
+        // psi(X, Y, Z) = (u^2 * S * (X * S + T * Z^4), u^3 * Y * S * (S^2 - T * Z^4), S * Z).
+        // The kernel of psi and the point at infinity are sent to Z = 0.
+        let [x0, t, u2, u3] = Self::ENDO_COEFFS else {
+            unreachable!()
+        };
+        let z2 = p.z.square();
+        let t_z4 = *t * z2.square();
+        let s = p.x - *x0 * z2;
+        let s2 = s.square();
+        SWProjective::new_unchecked(
+            *u2 * s * (p.x * s + t_z4),
+            *u3 * p.y * s * (s2 - t_z4),
+            s * p.z,
+        )
+    }
+
+    fn endomorphism_affine(p: &SWAffine) -> SWAffine {
+        let [x0, t, u2, u3] = Self::ENDO_COEFFS else {
+            unreachable!()
+        };
+        let s_inv = match (p.x - *x0).inverse() {
+            Some(s_inv) if !p.is_zero() => s_inv,
+            _ => return SWAffine::identity(),
+        };
+        let t_s_inv = *t * s_inv;
+        SWAffine::new_unchecked(
+            *u2 * (p.x + t_s_inv),
+            *u3 * p.y * (Fq::ONE - t_s_inv * s_inv),
+        )
+    }
+}
+
+/// SW_TORSION_X, the x-coordinate of a point of order 2 in short Weierstrass
+/// form other than the kernel (X0, 0) of psi.
+const SW_TORSION_X: Fq =
+    MontFp!("16243039716619667691992873570312140335529769388307850787230060081835532586707");
+
+// E(Fq) has full 2-torsion and cofactor 4, so its prime-order subgroup is
+// 2E(Fq). In short Weierstrass form, a point (x, y) of order greater than 2 is
+// in 2E(Fq) if and only if x - e is a square for each of the three roots e of
+// x^3 + A * x + B, by 2-descent. Their product is y^2, so two of them are
+// enough. These two Legendre symbols are much cheaper than a subgroup check
+// and tell `mul_projective` whether GLV can be used.
+fn sw_in_prime_order_subgroup(p: &SWProjective) -> bool {
+    // With x = X / Z^2, x - e is a square if and only if X - e * Z^2 is one.
+    let z2 = p.z.square();
+    p.is_zero()
+        || [BandersnatchConfig::ENDO_COEFFS[0], SW_TORSION_X]
+            .iter()
+            .all(|e| (p.x - *e * z2).legendre().is_qr())
+}
+
+fn te_in_prime_order_subgroup(p: &EdwardsProjective) -> bool {
+    // The Montgomery u-coordinate is (Z + Y) / (Z - Y), and x - e = (u - u_e) / B
+    // in short Weierstrass form, where u_e = 0 for X0 and u_e = TE_ENDO_B for
+    // SW_TORSION_X.
+    if p.is_zero() {
+        return true;
+    }
+    let (n, d) = (p.z + p.y, p.z - p.y);
+    let b_d = <BandersnatchConfig as MontCurveConfig>::COEFF_B * d;
+    (n * b_d).legendre().is_qr() && ((n - TE_ENDO_B * d) * b_d).legendre().is_qr()
+}
+
+/// TE_ENDO_B = 0x52c9f28b828426a561f00d3a63511a882ea712770d9af4d6ee0f014d172510b4
+const TE_ENDO_B: Fq =
+    MontFp!("37446463827641770816307242315180085052603635617490163568005256780843403514036");
+
+/// TE_ENDO_C = 0x6cc624cf865457c3a97c6efd6c17d1078456abcfff36f4e9515c806cdf650b3d
+const TE_ENDO_C: Fq =
+    MontFp!("49199877423542878313146170939139662862850515542392585932876811575731455068989");
+
+/// The endomorphism psi in twisted Edwards form, from [MSZ21, section 3]:
+/// psi(x, y) = (c * (1 - y^2) / (x * y), b * (y^2 + b) / (y^2 - b)),
+/// with b = TE_ENDO_B and c = TE_ENDO_C. It is the map of `GLVConfig`
+/// transported through the birational equivalence with the short Weierstrass
+/// form, and also multiplies the prime-order subgroup by LAMBDA.
+///
+/// [MSZ21]: https://eprint.iacr.org/2021/1152
+fn te_endomorphism(p: &EdwardsProjective) -> EdwardsProjective {
+    // The formula below is not defined when x * y = 0. The points with x = 0
+    // are the identity and (0, -1), which is the kernel of psi, and there are
+    // no points with y = 0 in E(Fq), as they would have order 4.
+    if p.x.is_zero() {
+        return EdwardsProjective::zero();
+    }
+    // With f = c * (Z^2 - Y^2), g = b * (Y^2 + b * Z^2), h = Y^2 - b * Z^2:
+    // psi(X, Y, T, Z) = (f * h, g * X * Y, f * g, h * X * Y).
+    let y2 = p.y.square();
+    let b_z2 = TE_ENDO_B * p.z.square();
+    let f = TE_ENDO_C * (p.z.square() - y2);
+    let g = TE_ENDO_B * (y2 + b_z2);
+    let h = y2 - b_z2;
+    let xy = p.x * p.y;
+    EdwardsProjective::new_unchecked(f * h, g * xy, f * g, h * xy)
+}
+
+/// Scalar multiplication in twisted Edwards form, using the decomposition of
+/// `k` given by the `GLVConfig` of the short Weierstrass form. As psi only acts
+/// as LAMBDA there, `p` must be in the prime-order subgroup.
+fn glv_mul_te(p: &EdwardsProjective, k: Fr) -> EdwardsProjective {
+    let ((sgn_k1, k1), (sgn_k2, k2)) = BandersnatchConfig::scalar_decomposition(k);
+
+    let mut b1 = *p;
+    let mut b2 = te_endomorphism(p);
+    if !sgn_k1 {
+        b1 = -b1;
+    }
+    if !sgn_k2 {
+        b2 = -b2;
+    }
+    let b1b2 = b1 + b2;
+
+    let iter_k1 = BitIteratorBE::new(k1.into_bigint());
+    let iter_k2 = BitIteratorBE::new(k2.into_bigint());
+
+    let mut res = EdwardsProjective::zero();
+    for pair in iter_k1
+        .zip(iter_k2)
+        .skip_while(|&pair| pair == (false, false))
+    {
+        res.double_in_place();
+        match pair {
+            (true, false) => res += b1,
+            (false, true) => res += b2,
+            (true, true) => res += b1b2,
+            (false, false) => {},
+        }
+    }
+    res
 }
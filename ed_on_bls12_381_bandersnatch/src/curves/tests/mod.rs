@@ -0,0 +1,297 @@
+use super::{sw_in_prime_order_subgroup, te_endomorphism, te_in_prime_order_subgroup};
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::twisted_edwards::MontCurveConfig;
+use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Map,
+    scalar_mul::{glv::GLVConfig, sw_double_and_add_projective},
+    AffineRepr, CurveGroup, PrimeGroup,
+};
+use ark_ff::{
+    AdditiveGroup, BigInt, BigInteger, BitIteratorBE, Field, PrimeField, UniformRand, Zero,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::{rand::Rng, test_rng};
+use sha2::Sha512;
+
+extern crate std;
+use std::{vec, vec::Vec};
+
+test_group!(te; EdwardsProjective; te);
+test_group!(sw_glv; SWProjective; glv);
+
+fn read_point(x: Fq, y: Fq) -> EdwardsAffine {
+    let p = EdwardsAffine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/bandersnatch_XMD-SHA-512_ELL2_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/bandersnatch_XMD-SHA-512_ELL2_NU_.json",
+        read_point,
+    );
+}
+
+/// Double-and-add in twisted Edwards form, independent of the GLV path.
+fn te_double_and_add(p: &EdwardsProjective, k: impl AsRef<[u64]>) -> EdwardsProjective {
+    let mut res = EdwardsProjective::zero();
+    for b in BitIteratorBE::without_leading_zeros(k) {
+        res.double_in_place();
+        if b {
+            res += p;
+        }
+    }
+    res
+}
+
+#[test]
+fn test_te_endomorphism() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..100 {
+        let p = EdwardsProjective::rand(&mut rng);
+        let psi = te_endomorphism(&p);
+        assert!(psi.into_affine().is_on_curve());
+        assert_eq!(
+            psi,
+            te_double_and_add(&p, BandersnatchConfig::LAMBDA.into_bigint())
+        );
+    }
+    assert!(te_endomorphism(&EdwardsProjective::zero()).is_zero());
+    // (0, -1) is the kernel of psi.
+    let t = EdwardsAffine::new_unchecked(Fq::zero(), -Fq::ONE);
+    assert!(te_endomorphism(&t.into()).is_zero());
+}
+
+#[test]
+fn test_sw_endomorphism() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..100 {
+        let p = SWProjective::rand(&mut rng);
+        let lambda_p =
+            sw_double_and_add_projective::<SWConfig>(&p, BandersnatchConfig::LAMBDA.into_bigint());
+        assert_eq!(BandersnatchConfig::endomorphism(&p), lambda_p);
+        assert_eq!(
+            BandersnatchConfig::endomorphism_affine(&p.into_affine()),
+            lambda_p.into_affine()
+        );
+    }
+    assert!(BandersnatchConfig::endomorphism(&SWProjective::zero()).is_zero());
+    assert!(BandersnatchConfig::endomorphism_affine(&SWAffine::zero()).is_zero());
+}
+
+#[test]
+fn test_te_glv_mul() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..100 {
+        let p = EdwardsProjective::rand(&mut rng);
+        let k = Fr::rand(&mut rng);
+        let expected = te_double_and_add(&p, k.into_bigint());
+        assert_eq!(p * k, expected);
+        assert_eq!(p.into_affine() * k, expected);
+    }
+    let g = EdwardsProjective::generator();
+    assert!((g * Fr::from(0u64)).is_zero());
+    assert_eq!(g * Fr::from(1u64), g);
+    assert_eq!(g * -Fr::from(1u64), -g);
+}
+
+/// The birational map from twisted Edwards to short Weierstrass form, through
+/// the Montgomery form. Defined away from the points of order dividing 2.
+fn te_to_sw(p: &EdwardsAffine) -> SWAffine {
+    let one = Fq::from(1u64);
+    let (a, b) = (
+        <BandersnatchConfig as MontCurveConfig>::COEFF_A,
+        <BandersnatchConfig as MontCurveConfig>::COEFF_B,
+    );
+    let u = (one + p.y) / (one - p.y);
+    let v = u / p.x;
+    let b_inv = b.inverse().unwrap();
+    let q = SWAffine::new_unchecked(u * b_inv + a * (b + b + b).inverse().unwrap(), v * b_inv);
+    assert!(q.is_on_curve());
+    q
+}
+
+#[test]
+fn test_te_sw_glv_mul_agree() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..100 {
+        let p = EdwardsAffine::rand(&mut rng);
+        let k = Fr::rand(&mut rng);
+        let q = te_to_sw(&p);
+        let expected = te_to_sw(&(p * k).into_affine());
+        assert_eq!((SWProjective::from(q) * k).into_affine(), expected);
+    }
+}
+
+/// Returns a uniformly random point of E(Fq) in twisted Edwards form, which
+/// usually has a small-order component.
+fn random_te_point<R: Rng>(rng: &mut R) -> EdwardsAffine {
+    loop {
+        if let Some(p) = EdwardsAffine::get_point_from_y_unchecked(Fq::rand(rng), rng.gen()) {
+            return p;
+        }
+    }
+}
+
+/// Returns the four points of E(Fq) of order dividing 2, in short Weierstrass
+/// form. Only the identity and (0, -1) have a twisted Edwards affine form.
+fn sw_small_order_points() -> Vec<SWAffine> {
+    let mut rng = test_rng();
+    let mut points = vec![SWAffine::zero()];
+    while points.len() < 4 {
+        let x = Fq::rand(&mut rng);
+        if let Some(p) = SWAffine::get_point_from_x_unchecked(x, rng.gen()) {
+            let t = p.mul_bigint(Fr::characteristic()).into_affine();
+            if !points.contains(&t) {
+                points.push(t);
+            }
+        }
+    }
+    points
+}
+
+/// Tests the subgroup check against multiplication by r.
+fn test_sw_subgroup_check(p: &SWAffine) -> bool {
+    assert!(p.is_on_curve());
+    let in_subgroup = p.mul_bigint(Fr::characteristic()).is_zero();
+    assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+    in_subgroup
+}
+
+/// Tests the subgroup check against multiplication by r, in both forms.
+fn test_te_subgroup_check(p: &EdwardsAffine) -> bool {
+    assert!(p.is_on_curve());
+    let in_subgroup = p.mul_bigint(Fr::characteristic()).is_zero();
+    assert_eq!(p.is_in_correct_subgroup_assuming_on_curve(), in_subgroup);
+    if !p.x.is_zero() {
+        assert_eq!(test_sw_subgroup_check(&te_to_sw(p)), in_subgroup);
+    }
+    in_subgroup
+}
+
+#[test]
+fn test_subgroup_membership() {
+    let mut rng = test_rng();
+    for _ in 0..20 {
+        assert!(test_te_subgroup_check(&EdwardsAffine::rand(&mut rng)));
+        assert!(test_sw_subgroup_check(&SWAffine::rand(&mut rng)));
+        test_te_subgroup_check(&random_te_point(&mut rng));
+    }
+    assert!(test_te_subgroup_check(&EdwardsAffine::generator()));
+    assert!(test_te_subgroup_check(&EdwardsAffine::zero()));
+    assert!(test_sw_subgroup_check(&SWAffine::generator()));
+    assert!(test_sw_subgroup_check(&SWAffine::zero()));
+}
+
+#[test]
+fn test_small_order_and_mixed_points() {
+    let mut rng = test_rng();
+    for t in sw_small_order_points() {
+        assert_eq!(test_sw_subgroup_check(&t), t.is_zero());
+        for _ in 0..5 {
+            let p = (SWProjective::rand(&mut rng) + t).into_affine();
+            assert_eq!(test_sw_subgroup_check(&p), t.is_zero());
+        }
+    }
+
+    let t = EdwardsAffine::new_unchecked(Fq::zero(), -Fq::ONE);
+    assert!(!test_te_subgroup_check(&t));
+    for _ in 0..5 {
+        let p = (EdwardsProjective::rand(&mut rng) + t).into_affine();
+        assert!(!test_te_subgroup_check(&p));
+    }
+    // Random points of E(Fq) have a component at infinity of order 2 in
+    // twisted Edwards form half of the time.
+    let mut non_members = 0;
+    while non_members < 10 {
+        if !test_te_subgroup_check(&random_te_point(&mut rng)) {
+            non_members += 1;
+        }
+    }
+}
+
+#[test]
+fn test_deserialization_rejects_points_outside_subgroup() {
+    let mut rng = test_rng();
+    let small_order = sw_small_order_points();
+    for compress in [Compress::Yes, Compress::No] {
+        for _ in 0..10 {
+            let p = random_te_point(&mut rng);
+            let mut bytes = Vec::new();
+            p.serialize_with_mode(&mut bytes, compress).unwrap();
+            let q = EdwardsAffine::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(q.is_ok(), p.mul_bigint(Fr::characteristic()).is_zero());
+            let q = EdwardsAffine::deserialize_with_mode(&bytes[..], compress, Validate::No);
+            assert_eq!(q.unwrap(), p);
+        }
+
+        for t in &small_order {
+            let p = (SWProjective::rand(&mut rng) + t).into_affine();
+            let mut bytes = Vec::new();
+            p.serialize_with_mode(&mut bytes, compress).unwrap();
+            let q = SWAffine::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(q.is_ok(), t.is_zero());
+            let q = SWAffine::deserialize_with_mode(&bytes[..], compress, Validate::No);
+            assert_eq!(q.unwrap(), p);
+        }
+    }
+}
+
+/// Scalars for which GLV would differ from double-and-add outside the
+/// prime-order subgroup, as it reduces them mod r.
+fn mul_test_scalars<R: Rng>(rng: &mut R) -> Vec<<Fr as PrimeField>::BigInt> {
+    let mut r_plus_one = Fr::MODULUS;
+    r_plus_one.add_with_carry(&1u64.into());
+    vec![
+        Fr::rand(rng).into_bigint(),
+        Fr::MODULUS,
+        r_plus_one,
+        BigInt::new(rng.gen()),
+    ]
+}
+
+#[test]
+fn test_mul_outside_subgroup() {
+    let mut rng = test_rng();
+
+    let t = EdwardsAffine::new_unchecked(Fq::zero(), -Fq::ONE);
+    let mut te_points = vec![t, EdwardsAffine::zero()];
+    for _ in 0..5 {
+        te_points.push((EdwardsProjective::rand(&mut rng) + t).into_affine());
+        te_points.push(random_te_point(&mut rng));
+    }
+    for p in te_points {
+        let in_subgroup = p.mul_bigint(Fr::characteristic()).is_zero();
+        assert_eq!(te_in_prime_order_subgroup(&p.into()), in_subgroup);
+        for k in mul_test_scalars(&mut rng) {
+            assert_eq!(
+                p.into_group().mul_bigint(k),
+                te_double_and_add(&p.into(), k)
+            );
+        }
+    }
+
+    for t in sw_small_order_points() {
+        for p in [t, (SWProjective::rand(&mut rng) + t).into_affine()] {
+            assert_eq!(sw_in_prime_order_subgroup(&p.into()), t.is_zero());
+            for k in mul_test_scalars(&mut rng) {
+                assert_eq!(
+                    p.into_group().mul_bigint(k),
+                    sw_double_and_add_projective(&p.into(), k.as_ref())
+                );
+            }
+        }
+    }
+}
@@ -1,8 +1,14 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::{Field, MontFp};
+use sha2::Sha512;
 
 use crate::{Fq, Fr};
 
@@ -12,6 +18,18 @@ mod tests;
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
+/// Hashes to the curve with the `ed_on_bn254_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "ed_on_bn254_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "ed_on_bn254_XMD:SHA-512_ELL2_NU_";
+
 /// `Baby-JubJub` is a twisted Edwards curve. These curves have equations of the
 /// form: ax² + y² = 1 + dx²y².
 /// over some base finite field Fq.
@@ -64,6 +82,24 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 5
+    const Z: Fq = MontFp!("5");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 434960922217718232343252710131411531147046209979785943225785176687629841089
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("434960922217718232343252710131411531147046209979785943225785176687629841089");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B =
+    /// 4853299424208772548686123611778859703392057609733030116540456584487579683386
+    const COEFF_A_OVER_COEFF_B: Fq =
+        MontFp!("4853299424208772548686123611778859703392057609733030116540456584487579683386");
+}
+
 /// GENERATOR_X =
 /// 19698561148652590122159747500897617769866003486955115824547446575314762165298
 pub const GENERATOR_X: Fq =
@@ -0,0 +1,30 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::hashing::curve_maps::elligator2::Elligator2Map;
+use sha2::Sha512;
+
+test_group!(te; EdwardsProjective; te);
+
+fn read_point(x: Fq, y: Fq) -> EdwardsAffine {
+    let p = EdwardsAffine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/ed_on_bn254_XMD-SHA-512_ELL2_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>(
+        "./src/curves/tests/ed_on_bn254_XMD-SHA-512_ELL2_NU_.json",
+        read_point,
+    );
+}
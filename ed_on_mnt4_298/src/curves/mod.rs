@@ -1,8 +1,14 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use sha2::Sha512;
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -12,6 +18,18 @@ mod tests;
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
+/// Hashes to the curve with the `ed_on_mnt4_298_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "ed_on_mnt4_298_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "ed_on_mnt4_298_XMD:SHA-512_ELL2_NU_";
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsConfig;
 
@@ -69,6 +87,22 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 5
+    const Z: Fq = MontFp!("5");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 208216000199051830017090296723208697550992134366860288054554634385884533196991214621093208
+    const ONE_OVER_COEFF_B_SQUARE: Fq = MontFp!("208216000199051830017090296723208697550992134366860288054554634385884533196991214621093208");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B =
+    /// 237961143084630662876674624826524225772562439276411757776633867869582323653704245279983674
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("237961143084630662876674624826524225772562439276411757776633867869582323653704245279983674");
+}
+
 /// GENERATOR_X =
 /// 282406820114868156776872298252698015906762052916420164316497572033519876761239463633892227
 pub const GENERATOR_X: Fq = MontFp!(
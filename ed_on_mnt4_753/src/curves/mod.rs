@@ -1,8 +1,14 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::elligator2::{Elligator2Config, Elligator2Map},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
+use sha2::Sha512;
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -12,6 +18,18 @@ mod tests;
 pub type EdwardsAffine = Affine<EdwardsConfig>;
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
+/// Hashes to the curve with the `ed_on_mnt4_753_XMD:SHA-512_ELL2_RO_` suite,
+/// with the DST passed to `HashToCurve::new`.
+pub type Hasher =
+    MapToCurveBasedHasher<EdwardsProjective, XmdFieldHasher<Sha512>, Elligator2Map<EdwardsConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "ed_on_mnt4_753_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "ed_on_mnt4_753_XMD:SHA-512_ELL2_NU_";
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsConfig;
 
@@ -56,6 +74,22 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// Parameters of the Elligator 2 map of
+// [RFC 9380, section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-elligator-2-method),
+// where Z is the output of `find_z_ell2` from appendix H.3.
+impl Elligator2Config for EdwardsConfig {
+    /// Z = 11
+    const Z: Fq = MontFp!("11");
+
+    /// ONE_OVER_COEFF_B_SQUARE = 1 / COEFF_B^2 =
+    /// 18330589798464542113525593971167778743574685589979852718905282527948591855499859213213266021658273278120406050201200583828888212652572311592992105811473423322503119642340653193143307935307799330455221877088709821846599516293218
+    const ONE_OVER_COEFF_B_SQUARE: Fq = MontFp!("18330589798464542113525593971167778743574685589979852718905282527948591855499859213213266021658273278120406050201200583828888212652572311592992105811473423322503119642340653193143307935307799330455221877088709821846599516293218");
+
+    /// COEFF_A_OVER_COEFF_B = COEFF_A / COEFF_B =
+    /// 20949245483959476701172107395620318564085354959976974535891751460512676406285553386529446881895169460709035485944229238661586528745796927534848120927398198082860708162675032220735209068923199234805967859529954082110392238238845
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("20949245483959476701172107395620318564085354959976974535891751460512676406285553386529446881895169460709035485944229238661586528745796927534848120927398198082860708162675032220735209068923199234805967859529954082110392238238845");
+}
+
 /// GENERATOR_X =
 /// 41126137307536311801428235632419266329480236393691483739251051053325519918069469184425962602019877935619960143044210127218431046103600632347238890180171944971817510488009355627861577881883236134824745174469522277738875418206826
 pub const GENERATOR_X: Fq =
@@ -4,9 +4,10 @@
 use crate::{fq::Fq, fr::Fr};
 use ark_ec::{
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 #[cfg(test)]
 mod tests;
@@ -42,6 +43,42 @@ impl SWCurveConfig for GrumpkinConfig {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+}
+
+impl GLVConfig for GrumpkinConfig {
+    /// BETA, a non-trivial cube root of unity in Fq. The endomorphism
+    /// (x, y) -> (BETA * x, y) acts on the points as multiplication by LAMBDA.
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "21888242871839275217838484774961031246154997185409878258781734729429964517155"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("21888242871839275220042445260109153167277707414472061641714758635765020556616");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("147946756881789319000765030803803410729")),
+        (false, BigInt!("9931322734385697762")),
+        (true, BigInt!("9931322734385697762")),
+        (true, BigInt!("147946756881789319010696353538189108491")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X = 1
@@ -1,9 +1,11 @@
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     mnt4,
     mnt4::MNT4Config,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    AffineRepr, CurveGroup, PrimeGroup,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, Field, MontFp};
 
 use crate::{Fq, Fq2, Fr, G1_COEFF_A_NON_RESIDUE};
 
@@ -63,6 +65,72 @@ impl SWCurveConfig for Config {
     fn mul_by_a(elt: Fq2) -> Fq2 {
         Fq2::new(MUL_BY_A_C0 * &elt.c0, MUL_BY_A_C1 * &elt.c1)
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        // psi acts on G2 as multiplication by q = t - 1 mod r. On E'(Fq2),
+        // psi^2 = -1, so (-psi - [t - 1]) o (psi - [t - 1]) = [(t - 1)^2 + 1] = [r]
+        // and the kernel of psi - [t - 1] is exactly G2.
+        //
+        // Checks that psi(P) = [t - 1]P
+        let p = point.into_group();
+        psi(&p) == mul_by_t_minus_one(&p)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // On E'(Fq2), psi^2 = -1 and psi^2 - [t]psi + [q] = 0, which give
+        // [t] o (psi - [t - 1]) = 0. As psi - [t - 1] is invertible outside G2,
+        // [t] kills the part of E'(Fq2) outside G2. It is nonzero on G2, and
+        // much cheaper than COFACTOR = t^2. The sign of t does not matter here.
+        p.mul_bigint(T).into_affine()
+    }
+}
+
+// Parameters of the simplified SWU map of
+// [RFC 9380, section 6.6.2](https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-shallue-van-de-w),
+// which applies directly since COEFF_A and COEFF_B are both nonzero.
+impl SWUConfig for Config {
+    /// ZETA = u, computed with find_z_sswu from appendix H.2
+    const ZETA: Fq2 = Fq2::new(Fq::ZERO, Fq::ONE);
+}
+
+/// T = |t|, where t is the trace of Frobenius of the curve over Fq:
+/// t = 689871209842287392837045615510547309923794945
+const T: &[u64] = &[0xdc9a1b671660001, 0x46609756bec2a33f, 0x1eef55];
+
+/// T_MINUS_ONE = |t - 1| = 689871209842287392837045615510547309923794944
+const T_MINUS_ONE: &[u64] = &[0xdc9a1b671660000, 0x46609756bec2a33f, 0x1eef55];
+
+/// Whether t - 1 is negative.
+const T_MINUS_ONE_IS_NEGATIVE: bool = false;
+
+/// Multiplies `p` by t - 1, which is q mod r.
+fn mul_by_t_minus_one(p: &G2Projective) -> G2Projective {
+    let res = p.mul_bigint(T_MINUS_ONE);
+    if T_MINUS_ONE_IS_NEGATIVE {
+        -res
+    } else {
+        res
+    }
+}
+
+/// PSI_COEFF_Y = TWIST^(3 * (1 - q) / 2)
+/// 7684163245453501615621351552473337069301082060976805004625011694147890954040864167002308
+const PSI_COEFF_Y: Fq = MontFp!(
+    "7684163245453501615621351552473337069301082060976805004625011694147890954040864167002308"
+);
+
+/// psi(x, y) = (-x^q, PSI_COEFF_Y * y^q) is the untwist-Frobenius-twist
+/// endomorphism of G2, where -1 = TWIST^(1 - q).
+pub fn psi(p: &G2Projective) -> G2Projective {
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.y.frobenius_map_in_place(1);
+    res.z.frobenius_map_in_place(1);
+    res.x = -res.x;
+    res.y.mul_assign_by_basefield(&PSI_COEFF_Y);
+    res
 }
 
 const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
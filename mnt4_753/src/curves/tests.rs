@@ -1,7 +1,51 @@
 use crate::*;
 use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::groups::subgroup_check_test;
+use ark_ec::{
+    hashing::{curve_maps::swu::SWUMap, map_to_curve_hasher::MapToCurve},
+    AffineRepr, CurveGroup, PrimeGroup,
+};
+use ark_ff::{Field, UniformRand, Zero};
+use ark_std::{rand::Rng, test_rng};
 
 test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<MNT4_753>; msm);
 test_pairing!(pairing; crate::MNT4_753);
+
+#[test]
+fn test_g2_psi() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        // psi acts on G2 as multiplication by q.
+        let p = G2Projective::rand(&mut rng);
+        assert_eq!(g2::psi(&p), p.mul_bigint(Fq::characteristic()));
+
+        // psi^2 = -1 on all of E'(Fq2).
+        let x = Fq2::rand(&mut rng);
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, rng.gen()) {
+            let p = p.into_group();
+            assert!(g2::psi(&p).into_affine().is_on_curve());
+            assert_eq!(g2::psi(&g2::psi(&p)), -p);
+        }
+    }
+}
+
+#[test]
+fn test_g2_subgroup_check_and_cofactor_clearing() {
+    subgroup_check_test::<g2::Config>(&[]);
+}
+
+#[test]
+fn test_g2_swu_map() {
+    SWUMap::<g2::Config>::check_parameters().unwrap();
+
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = SWUMap::<g2::Config>::map_to_curve(Fq2::rand(&mut rng)).unwrap();
+        assert!(p.is_on_curve());
+        let p = p.clear_cofactor();
+        assert!(p.mul_bigint(Fr::characteristic()).is_zero());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
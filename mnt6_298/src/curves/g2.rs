@@ -1,11 +1,13 @@
 use ark_ec::{
+    hashing::curve_maps::swu::SWUConfig,
     mnt6,
     mnt6::MNT6Config,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    AffineRepr, CurveGroup, PrimeGroup,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, Field, Fp3Config, MontFp};
 
-use crate::{g1, Fq, Fq3, Fr};
+use crate::{g1, Fq, Fq3, Fq3Config, Fr};
 
 pub type G2Affine = mnt6::G2Affine<crate::Config>;
 pub type G2Projective = mnt6::G2Projective<crate::Config>;
@@ -70,6 +72,66 @@ impl SWCurveConfig for Config {
             MUL_BY_A_C2 * &elt.c0,
         )
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+        // psi acts on G2 as multiplication by q = t - 1 mod r. On E'(Fq3),
+        // psi^3 = -1, so with c = t - 1,
+        // (psi - [c]) o (psi^2 + [c]psi + [c^2]) = -[1 + c^3] = -[t * r].
+        // As t is coprime to the cofactor, the kernel of psi - [c] is exactly G2.
+        //
+        // Checks that psi(P) = [t - 1]P
+        let p = point.into_group();
+        psi(&p) == mul_by_t_minus_one(&p)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        // On E'(Fq3), psi^3 = -1 and psi^2 - [t]psi + [q] = 0, which give
+        // [t - 1] o (psi + 1) o (psi - [t - 1]) = 0. As psi - [t - 1] is
+        // invertible outside G2, [t - 1] o (psi + 1) kills the part of E'(Fq3)
+        // outside G2, and it is nonzero on G2.
+        let p = p.into_group();
+        mul_by_t_minus_one(&(psi(&p) + p)).into_affine()
+    }
+}
+
+// Parameters of the simplified SWU map of
+// [RFC 9380, section 6.6.2](https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-shallue-van-de-w),
+// which applies directly since COEFF_A and COEFF_B are both nonzero.
+impl SWUConfig for Config {
+    /// ZETA = -9 - u, computed with find_z_sswu from appendix H.2
+    const ZETA: Fq3 = Fq3::new(MontFp!("-9"), MontFp!("-1"), Fq::ZERO);
+}
+
+/// T_MINUS_ONE = |t - 1| = 689871209842287392837045615510547309923794944,
+/// where t is the trace of Frobenius of the curve over Fq
+const T_MINUS_ONE: &[u64] = &[0xdc9a1b671660000, 0x46609756bec2a33f, 0x1eef55];
+
+/// Whether t - 1 is negative.
+const T_MINUS_ONE_IS_NEGATIVE: bool = true;
+
+/// Multiplies `p` by t - 1, which is q mod r.
+fn mul_by_t_minus_one(p: &G2Projective) -> G2Projective {
+    let res = p.mul_bigint(T_MINUS_ONE);
+    if T_MINUS_ONE_IS_NEGATIVE {
+        -res
+    } else {
+        res
+    }
+}
+
+/// psi(x, y) = (FROBENIUS_COEFF_FP3_C1[2] * x^q, -y^q) is the
+/// untwist-Frobenius-twist endomorphism of G2.
+pub fn psi(p: &G2Projective) -> G2Projective {
+    let mut res = *p;
+    res.x.frobenius_map_in_place(1);
+    res.y.frobenius_map_in_place(1);
+    res.z.frobenius_map_in_place(1);
+    res.x
+        .mul_assign_by_base_field(&Fq3Config::FROBENIUS_COEFF_FP3_C1[2]);
+    res.y = -res.y;
+    res
 }
 
 const G2_GENERATOR_X: Fq3 = Fq3::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1, G2_GENERATOR_X_C2);
@@ -1,12 +1,20 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::wb::{IsogenyMap, WBConfig, WBMap},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
+use blake2::Blake2b512;
 
 use crate::{fq::Fq, fr::Fr};
 
+mod swu_iso;
+
 #[cfg(test)]
 mod tests;
 
@@ -27,6 +35,18 @@ impl CurveConfig for PallasConfig {
 pub type Affine = sw::Affine<PallasConfig>;
 pub type Projective = sw::Projective<PallasConfig>;
 
+/// `hash_to_field` of the `pallas_XMD:BLAKE2b_SSWU_RO_` suite, which uses
+/// BLAKE2b-512 and 64 bytes per field element.
+pub type FieldHasher = XmdFieldHasher<Blake2b512, 256>;
+
+/// Hashes to the curve as the `GroupHash` of the Zcash protocol
+/// specification, section 5.4.9.8, and `pasta_curves`, when passed the DST
+/// `domain_prefix || "-" || SUITE_ID`.
+pub type Hasher = MapToCurveBasedHasher<Projective, FieldHasher, WBMap<PallasConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "pallas_XMD:BLAKE2b_SSWU_RO_";
+
 impl SWCurveConfig for PallasConfig {
     /// COEFF_A = 0
     const COEFF_A: Fq = Fq::ZERO;
@@ -43,6 +63,15 @@ impl SWCurveConfig for PallasConfig {
     }
 }
 
+// Parameters of the `hash_to_curve` of [pasta_curves](https://github.com/zcash/pasta_curves),
+// used by Zcash Orchard: the simplified SWU map to iso-Pallas followed by a 3-isogeny.
+impl WBConfig for PallasConfig {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_PALLAS;
+}
+
 impl GLVConfig for PallasConfig {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
         "20444556541222657078399132219657928148671392403212669005631716460534733845831"
@@ -0,0 +1,38 @@
+use crate::{Affine, FieldHasher, Fq, Hasher, PallasConfig, Projective, SUITE_ID};
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_ec::hashing::{curve_maps::wb::WBMap, HashToCurve};
+use ark_ff::{Field, PrimeField};
+
+test_group!(g1; Projective; sw);
+test_group!(g1_glv; Projective; glv);
+
+fn read_point(x: Fq, y: Fq) -> Affine {
+    let p = Affine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<Projective, FieldHasher, WBMap<PallasConfig>>(
+        "./src/curves/tests/pallas_XMD-BLAKE2b_SSWU_RO_.json",
+        read_point,
+    );
+}
+
+/// The `hash_to_curve` test vector of pasta_curves, which is given in
+/// Jacobian coordinates (x, y, z), i.e. the affine point (x / z^2, y / z^3).
+#[test]
+fn test_hash_to_curve_pasta_curves() {
+    let read_fq = |hex_str: &str| Fq::from_be_bytes_mod_order(&hex::decode(hex_str).unwrap());
+    let x = read_fq("36a6e3a9c50b7b6540cb002c977c82f37f8a875fb51eb35327ee1452e6ce7947");
+    let y = read_fq("01da3b4403d73252f2d7e9c19bc23dc6a080f2d02f8262fca4f7e3d756ac6a7c");
+    let z = read_fq("1d48103df8fcbb70d1809c1806c95651dd884a559fec0549658537ce9d94bed9");
+    let z_inv = z.inverse().unwrap();
+    let expected = Affine::new(x * z_inv.square(), y * z_inv.square() * z_inv);
+
+    let dst = ["z.cash:test-", SUITE_ID].concat();
+    let hasher = Hasher::new(dst.as_bytes()).unwrap();
+    assert_eq!(hasher.hash(b"Trans rights now!").unwrap(), expected);
+}
@@ -0,0 +1,242 @@
+//! Schnorr signatures and x-only public keys of [BIP-340], together with the
+//! key tweaking of [BIP-341].
+//!
+//! A public key is the 32-byte x-coordinate of a point, and stands for the
+//! point with that x-coordinate and an even y-coordinate. A signature is the
+//! x-coordinate of the commitment `R` followed by the scalar `s`. All
+//! integers are encoded as 32-byte big-endian strings, and all hashes are
+//! SHA-256 tagged hashes.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//! [BIP-341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, PrimeField, UniformRand, Zero};
+use ark_std::{fmt, rand::Rng, vec::Vec};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    sec1::{self, decode_be},
+    Affine, Fq, Fr, Projective,
+};
+
+/// Size in bytes of an x-only public key.
+pub const PUBLIC_KEY_SIZE: usize = 32;
+/// Size in bytes of a signature.
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// Errors returned when signing, verifying or tweaking keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The secret key is zero.
+    InvalidSecretKey,
+    /// The public key is not the x-coordinate of a point on the curve.
+    InvalidPublicKey,
+    /// The signature is malformed or does not verify.
+    InvalidSignature,
+    /// The tweak is not smaller than the group order, or yields the point at
+    /// infinity or a zero secret key.
+    InvalidTweak,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSecretKey => write!(f, "invalid secret key"),
+            Error::InvalidPublicKey => write!(f, "invalid public key"),
+            Error::InvalidSignature => write!(f, "signature verification failed"),
+            Error::InvalidTweak => write!(f, "invalid tweak"),
+        }
+    }
+}
+
+/// The tagged hash of BIP-340, SHA-256(SHA-256(tag) || SHA-256(tag) || data),
+/// where `data` is the concatenation of `parts`.
+pub fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Returns whether the y-coordinate of `p` is even. `p` must not be the
+/// point at infinity.
+pub fn has_even_y(p: &Affine) -> bool {
+    p.y.into_bigint().is_even()
+}
+
+/// Returns the x-coordinate of `p`, which must not be the point at infinity,
+/// as an x-only public key.
+pub fn x_only(p: &Affine) -> [u8; PUBLIC_KEY_SIZE] {
+    let mut bytes = [0u8; PUBLIC_KEY_SIZE];
+    bytes.copy_from_slice(&p.x.into_bigint().to_bytes_be());
+    bytes
+}
+
+/// The `lift_x` function of BIP-340: returns the point with x-coordinate `x`
+/// and an even y-coordinate.
+pub fn lift_x(x: &[u8; PUBLIC_KEY_SIZE]) -> Result<Affine, Error> {
+    let x = decode_be::<Fq>(x).ok_or(Error::InvalidPublicKey)?;
+    let p = Affine::get_point_from_x_unchecked(x, false).ok_or(Error::InvalidPublicKey)?;
+    Ok(if has_even_y(&p) { p } else { -p })
+}
+
+/// Returns the challenge `e = int(hash_BIP0340/challenge(r || P || m)) mod n`.
+fn challenge(r: &[u8], public: &[u8; PUBLIC_KEY_SIZE], message: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(&tagged_hash("BIP0340/challenge", &[r, public, message]))
+}
+
+/// Returns the secret key negated if needed so that its public key has an
+/// even y-coordinate, together with that public key.
+fn normalize_secret_key(secret: &Fr) -> Result<(Fr, Affine), Error> {
+    if secret.is_zero() {
+        return Err(Error::InvalidSecretKey);
+    }
+    let p = (Affine::generator() * secret).into_affine();
+    if has_even_y(&p) {
+        Ok((*secret, p))
+    } else {
+        Ok((-*secret, -p))
+    }
+}
+
+/// Returns the x-only public key of `secret`.
+pub fn public_key(secret: &Fr) -> Result<[u8; PUBLIC_KEY_SIZE], Error> {
+    Ok(x_only(&normalize_secret_key(secret)?.1))
+}
+
+/// Signs `message` with the default signing algorithm of BIP-340, using
+/// `aux_rand` as auxiliary randomness.
+pub fn sign(
+    secret: &Fr,
+    message: &[u8],
+    aux_rand: &[u8; 32],
+) -> Result<[u8; SIGNATURE_SIZE], Error> {
+    let (d, p) = normalize_secret_key(secret)?;
+    let public = x_only(&p);
+
+    let mut t = sec1::encode_scalar(&d);
+    for (t, a) in t.iter_mut().zip(tagged_hash("BIP0340/aux", &[aux_rand])) {
+        *t ^= a;
+    }
+    let rand = tagged_hash("BIP0340/nonce", &[&t, &public, message]);
+    let k = Fr::from_be_bytes_mod_order(&rand);
+    if k.is_zero() {
+        return Err(Error::InvalidSecretKey);
+    }
+    let r = (Affine::generator() * k).into_affine();
+    let k = if has_even_y(&r) { k } else { -k };
+    let r = x_only(&r);
+
+    let e = challenge(&r, &public, message);
+    let mut signature = [0u8; SIGNATURE_SIZE];
+    signature[..32].copy_from_slice(&r);
+    signature[32..].copy_from_slice(&sec1::encode_scalar(&(k + e * d)));
+    Ok(signature)
+}
+
+/// Decodes the public key and the scalar `s` of a signature, and computes its
+/// challenge.
+fn parse(
+    public: &[u8; PUBLIC_KEY_SIZE],
+    message: &[u8],
+    signature: &[u8; SIGNATURE_SIZE],
+) -> Result<(Affine, Fr, Fr), Error> {
+    let p = lift_x(public)?;
+    let s = sec1::decode_scalar(&signature[32..]).map_err(|_| Error::InvalidSignature)?;
+    let e = challenge(&signature[..32], public, message);
+    Ok((p, s, e))
+}
+
+/// Verifies a signature on `message` with the verification algorithm of
+/// BIP-340.
+pub fn verify(
+    public: &[u8; PUBLIC_KEY_SIZE],
+    message: &[u8],
+    signature: &[u8; SIGNATURE_SIZE],
+) -> Result<(), Error> {
+    let (p, s, e) = parse(public, message, signature)?;
+    let r = decode_be::<Fq>(&signature[..32]).ok_or(Error::InvalidSignature)?;
+    let big_r = (Affine::generator() * s - p * e).into_affine();
+    if big_r.is_zero() || !has_even_y(&big_r) || big_r.x != r {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(())
+}
+
+/// Verifies a batch of signatures with the batch verification algorithm of
+/// BIP-340, using a single multi-scalar multiplication.
+///
+/// The batch is accepted if and only if, except with negligible probability,
+/// each signature is accepted by [`verify`].
+pub fn verify_batch<R: Rng>(
+    items: &[(&[u8; PUBLIC_KEY_SIZE], &[u8], &[u8; SIGNATURE_SIZE])],
+    rng: &mut R,
+) -> Result<(), Error> {
+    let mut bases = Vec::with_capacity(2 * items.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_sum = Fr::zero();
+    for (i, (public, message, signature)) in items.iter().enumerate() {
+        let (p, s, e) = parse(public, message, signature)?;
+        let r = lift_x(signature[..32].try_into().unwrap()).map_err(|_| Error::InvalidSignature)?;
+        let a = if i == 0 {
+            Fr::from(1u64)
+        } else {
+            Fr::rand(rng)
+        };
+        s_sum += a * s;
+        bases.push(r);
+        scalars.push(a);
+        bases.push(p);
+        scalars.push(a * e);
+    }
+    bases.push(Affine::generator());
+    scalars.push(-s_sum);
+
+    if Projective::msm_unchecked(&bases, &scalars).is_zero() {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Returns the tweak `t = int(hash_TapTweak(P || h))` of BIP-341, where `h`
+/// is the Merkle root of the script tree, or empty for a key-path-only
+/// output.
+fn taproot_tweak(
+    public: &[u8; PUBLIC_KEY_SIZE],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<Fr, Error> {
+    let h = merkle_root.map_or(&[][..], |h| &h[..]);
+    decode_be::<Fr>(&tagged_hash("TapTweak", &[public, h])).ok_or(Error::InvalidTweak)
+}
+
+/// The `taproot_tweak_pubkey` function of BIP-341: returns the x-only output
+/// key `Q = P + [t]G` for the internal key `P`, and whether `Q` has an odd
+/// y-coordinate.
+pub fn tweak_public_key(
+    internal: &[u8; PUBLIC_KEY_SIZE],
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<([u8; PUBLIC_KEY_SIZE], bool), Error> {
+    let t = taproot_tweak(internal, merkle_root)?;
+    let q = (lift_x(internal)? + Affine::generator() * t).into_affine();
+    if q.is_zero() {
+        return Err(Error::InvalidTweak);
+    }
+    Ok((x_only(&q), !has_even_y(&q)))
+}
+
+/// The `taproot_tweak_seckey` function of BIP-341: returns the secret key of
+/// the output key of [`tweak_public_key`] for the internal key of `secret`.
+pub fn tweak_secret_key(secret: &Fr, merkle_root: Option<&[u8; 32]>) -> Result<Fr, Error> {
+    let (d, p) = normalize_secret_key(secret)?;
+    let tweaked = d + taproot_tweak(&x_only(&p), merkle_root)?;
+    if tweaked.is_zero() {
+        return Err(Error::InvalidTweak);
+    }
+    Ok(tweaked)
+}
@@ -1,17 +1,41 @@
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::wb::{IsogenyMap, WBConfig, WBMap},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
+
+use sha2::Sha256;
 
 use crate::{fq::Fq, fr::Fr};
 
+pub use ark_curve_utils::{ecdsa, sec1};
+
+pub mod bip340;
+mod swu_iso;
+
 #[cfg(test)]
 mod tests;
 
 pub type Affine = sw::Affine<Config>;
 pub type Projective = sw::Projective<Config>;
 
+/// Hashes to the curve with the `secp256k1_XMD:SHA-256_SSWU_RO_` suite of
+/// RFC 9380, section 8.7, with the DST passed to `HashToCurve::new`.
+pub type Hasher = MapToCurveBasedHasher<Projective, XmdFieldHasher<Sha256>, WBMap<Config>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// The suite ID of the nonuniform `encode_to_curve` suite, which uses the
+/// same field hasher and map as [`Hasher`] with a single field element.
+pub const SUITE_ID_NU: &str = "secp256k1_XMD:SHA-256_SSWU_NU_";
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Config;
 
@@ -41,6 +65,56 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+}
+
+impl GLVConfig for Config {
+    /// BETA, a non-trivial cube root of unity in Fq. The endomorphism
+    /// (x, y) -> (BETA * x, y) acts on the points as multiplication by LAMBDA.
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("37718080363155996902926221483475020450927657555482586988616620542887997980018");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709077")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468248")),
+        (true, BigInt!("64502973549206556628585045361533709077")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+}
+
+// Parameters from [RFC 9380, section 8.7](https://www.rfc-editor.org/rfc/rfc9380.html#name-secp256k1).
+impl WBConfig for Config {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_SECP256K1;
 }
 
 /// G_GENERATOR_X =
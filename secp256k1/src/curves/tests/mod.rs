@@ -0,0 +1,261 @@
+use crate::{bip340, ecdsa, sec1, Affine, Config, Fq, Fr, Projective};
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::{
+    ecdsa::{ecdsa_test, wycheproof_ecdsa_test},
+    hashing::hash_to_curve_test,
+    sec1::{sec1_generator_multiples_test, sec1_test},
+};
+use ark_curve_utils::field_hasher::XmdFieldHasher;
+use ark_ec::{hashing::curve_maps::wb::WBMap, AffineRepr, CurveGroup};
+use ark_ff::One;
+use ark_std::{test_rng, UniformRand};
+
+extern crate std;
+use std::{fs::File, io::BufReader, vec::Vec};
+
+use sha2::Sha256;
+
+test_group!(g1; Projective; sw);
+test_group!(g1_glv; Projective; glv);
+
+fn read_point(x: Fq, y: Fq) -> Affine {
+    let p = Affine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<Projective, XmdFieldHasher<Sha256>, WBMap<Config>>(
+        "./src/curves/tests/secp256k1_XMD-SHA-256_SSWU_RO_.json",
+        read_point,
+    );
+}
+
+#[test]
+fn test_encode_to_curve() {
+    hash_to_curve_test::<Projective, XmdFieldHasher<Sha256>, WBMap<Config>>(
+        "./src/curves/tests/secp256k1_XMD-SHA-256_SSWU_NU_.json",
+        read_point,
+    );
+}
+
+/// Checks the SEC 1 encodings against the multiples of the generator of
+/// the secp256k1 test vectors of Chuck Batson, as vendored by the RustCrypto `k256` crate.
+#[test]
+fn test_sec1_generator_multiples() {
+    sec1_generator_multiples_test::<Config>("./src/curves/tests/sec1_generator_multiples.json");
+}
+
+/// Checks the SEC 1 point decoding against the public key parsing tests of
+/// libsecp256k1; see the header of the file for the cases derived from them.
+#[test]
+fn test_sec1() {
+    sec1_test::<Config>("./src/curves/tests/sec1_public_key_test.json");
+}
+
+#[test]
+fn test_ecdsa_sign_verify() {
+    ecdsa_test::<Config, Sha256>();
+}
+
+/// Checks the Wycheproof vectors vendored by the RustCrypto `k256` crate; see
+/// the header of the file for the cases left out.
+#[test]
+fn test_ecdsa_wycheproof() {
+    wycheproof_ecdsa_test::<Config, Sha256>(
+        "./src/curves/tests/ecdsa_secp256k1_sha256_p1363_test.json",
+    );
+}
+
+/// secp256k1 is not covered by RFC 6979, so this checks the widely used
+/// vector for the secret key 1 and the message "Satoshi Nakamoto", whose
+/// signature is usually given in low-S form.
+#[test]
+fn test_ecdsa_rfc6979() {
+    let secret = Fr::one();
+    let (sig, recovery_id) = ecdsa::sign::<Config, Sha256>(&secret, b"Satoshi Nakamoto").unwrap();
+    let (low, negated) = sig.normalize_s();
+    assert!(negated);
+    assert_eq!(
+        low.to_bytes(),
+        hex::decode(
+            "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+             2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        ecdsa::recover::<Config, Sha256>(b"Satoshi Nakamoto", &low, recovery_id ^ 1),
+        Ok(Affine::generator())
+    );
+}
+
+/// Wycheproof case 284 is signed with a nonce whose point `R` has an
+/// x-coordinate larger than `n`, so that only a recovery id of 2 or 3 recovers
+/// the public key.
+#[test]
+fn test_ecdsa_recovery_large_x() {
+    let file = File::open("./src/curves/tests/ecdsa_secp256k1_sha256_p1363_test.json").unwrap();
+    let data: serde_json::Value = serde_json::from_reader(BufReader::new(file)).unwrap();
+    let hex = |v: &serde_json::Value| hex::decode(v.as_str().unwrap()).unwrap();
+    for group in data["testGroups"].as_array().unwrap() {
+        let public = sec1::decode_point(&hex(&group["key"]["uncompressed"])).unwrap();
+        for test in group["tests"].as_array().unwrap() {
+            if test["tcId"] != 284 {
+                continue;
+            }
+            let msg = hex(&test["msg"]);
+            let sig = ecdsa::Signature::from_bytes(&hex(&test["sig"])).unwrap();
+            let ids = (0..4)
+                .filter(|&id| ecdsa::recover::<Config, Sha256>(&msg, &sig, id) == Ok(public))
+                .collect::<Vec<_>>();
+            assert_eq!(ids.len(), 1);
+            assert!(ids[0] >= 2);
+            return;
+        }
+    }
+    panic!("missing Wycheproof case 284");
+}
+
+fn read_bytes<const N: usize>(hex_str: &str) -> [u8; N] {
+    hex::decode(hex_str).unwrap().try_into().unwrap()
+}
+
+/// Checks the BIP-340 test vectors, whose CSV columns are the index, the
+/// secret key, the public key, the auxiliary randomness, the message, the
+/// signature, the verification result and a comment.
+#[test]
+fn test_bip340_vectors() {
+    let data = std::fs::read_to_string("./src/curves/tests/bip340_test_vectors.csv").unwrap();
+    let mut rng = test_rng();
+    let mut valid = Vec::new();
+    for line in data.lines().skip(1) {
+        let fields = line.splitn(8, ',').collect::<Vec<_>>();
+        let public = read_bytes::<32>(fields[2]);
+        let message = hex::decode(fields[4]).unwrap();
+        let signature = read_bytes::<64>(fields[5]);
+        let expected = fields[6] == "TRUE";
+
+        if !fields[1].is_empty() {
+            let secret = sec1::decode_scalar(&hex::decode(fields[1]).unwrap()).unwrap();
+            let aux_rand = read_bytes::<32>(fields[3]);
+            assert_eq!(
+                bip340::public_key(&secret),
+                Ok(public),
+                "index {}",
+                fields[0]
+            );
+            assert_eq!(
+                bip340::sign(&secret, &message, &aux_rand),
+                Ok(signature),
+                "index {}",
+                fields[0]
+            );
+        }
+        let result = bip340::verify(&public, &message, &signature);
+        assert_eq!(
+            result.is_ok(),
+            expected,
+            "index {}: {}",
+            fields[0],
+            fields[7]
+        );
+        let batch = bip340::verify_batch(&[(&public, &message, &signature)], &mut rng);
+        assert_eq!(
+            batch.is_ok(),
+            expected,
+            "index {}: {}",
+            fields[0],
+            fields[7]
+        );
+        if expected {
+            valid.push((public, message, signature));
+        }
+    }
+
+    let mut items = valid
+        .iter()
+        .map(|(public, message, signature)| (public, message.as_slice(), signature))
+        .collect::<Vec<_>>();
+    assert!(bip340::verify_batch(&items, &mut rng).is_ok());
+    let message = items[1].1.to_vec();
+    items[2].1 = &message;
+    assert_eq!(
+        bip340::verify_batch(&items, &mut rng),
+        Err(bip340::Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_bip340_lift_x() {
+    let mut rng = test_rng();
+    for _ in 0..10 {
+        let p = Projective::rand(&mut rng).into_affine();
+        let lifted = bip340::lift_x(&bip340::x_only(&p)).unwrap();
+        assert!(bip340::has_even_y(&lifted));
+        assert!(lifted == p || lifted == -p);
+    }
+    // x = 5 is not the x-coordinate of a point, and p exceeds the field size.
+    let mut five = [0u8; 32];
+    five[31] = 5;
+    assert_eq!(bip340::lift_x(&five), Err(bip340::Error::InvalidPublicKey));
+    let modulus =
+        read_bytes::<32>("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+    assert_eq!(
+        bip340::lift_x(&modulus),
+        Err(bip340::Error::InvalidPublicKey)
+    );
+}
+
+/// The key-path vectors of the BIP-341 wallet test vectors.
+#[test]
+fn test_bip341_tweak() {
+    let vectors = [
+        (
+            "d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d",
+            None,
+            "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343",
+        ),
+        (
+            "187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27",
+            Some("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"),
+            "147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3",
+        ),
+    ];
+    for (internal, merkle_root, output) in vectors {
+        let merkle_root = merkle_root.map(read_bytes::<32>);
+        let (tweaked, _) =
+            bip340::tweak_public_key(&read_bytes(internal), merkle_root.as_ref()).unwrap();
+        assert_eq!(tweaked, read_bytes::<32>(output));
+    }
+
+    let secret = sec1::decode_scalar(
+        &hex::decode("6b973d88838f27366ed61c9ad6367663045cb456e28335c109e30717ae0c6baa").unwrap(),
+    )
+    .unwrap();
+    let internal = bip340::public_key(&secret).unwrap();
+    assert_eq!(
+        internal,
+        read_bytes::<32>("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d")
+    );
+    let tweaked = bip340::tweak_secret_key(&secret, None).unwrap();
+    assert_eq!(
+        sec1::encode_scalar(&tweaked),
+        read_bytes::<32>("2405b971772ad26915c8dcdf10f238753a9b837e5f8e6a86fd7c0cce5b7296d9")
+    );
+
+    // A key-path spend signs with the tweaked secret key for the output key.
+    let mut rng = test_rng();
+    for merkle_root in [None, Some(read_bytes::<32>(&"11".repeat(32)))] {
+        let secret = Fr::rand(&mut rng);
+        let internal = bip340::public_key(&secret).unwrap();
+        let (output, parity) = bip340::tweak_public_key(&internal, merkle_root.as_ref()).unwrap();
+        let tweaked = bip340::tweak_secret_key(&secret, merkle_root.as_ref()).unwrap();
+        let q = (Affine::generator() * tweaked).into_affine();
+        assert_eq!(bip340::x_only(&q), output);
+        assert_eq!(!bip340::has_even_y(&q), parity);
+        let signature = bip340::sign(&tweaked, b"key path", &[0u8; 32]).unwrap();
+        assert!(bip340::verify(&output, b"key path", &signature).is_ok());
+    }
+}
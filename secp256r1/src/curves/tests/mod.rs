@@ -0,0 +1,84 @@
+use crate::{ecdsa, sec1, Config, Fr, Projective};
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::{
+    ecdsa::{ecdsa_test, wycheproof_ecdsa_test},
+    sec1::{sec1_generator_multiples_test, sec1_test},
+};
+use ark_ff::PrimeField;
+use sha2::{digest::core_api::BlockSizeUser, Digest, Sha256, Sha512};
+
+test_group!(g1; Projective; sw);
+
+/// Checks the SEC 1 encodings against the multiples of the generator of
+/// the NIST P-256 test vectors of <http://point-at-infinity.org/ecc/nisttv>,
+/// as vendored by the RustCrypto `p256` crate.
+#[test]
+fn test_sec1_generator_multiples() {
+    sec1_generator_multiples_test::<Config>("./src/curves/tests/sec1_generator_multiples.json");
+}
+
+/// Checks the SEC 1 point decoding against the P-256 public key validation
+/// tests of `ring` and NIST CAVS; see the header of the file for the cases
+/// derived from them.
+#[test]
+fn test_sec1() {
+    sec1_test::<Config>("./src/curves/tests/sec1_public_key_test.json");
+}
+
+/// Checks a signature of RFC 6979, appendix A.2.
+fn check_rfc6979<D: Digest + BlockSizeUser>(secret: &str, message: &str, r: &str, s: &str) {
+    let secret = Fr::from_be_bytes_mod_order(&hex::decode(secret).unwrap());
+    let public = ecdsa::public_key::<Config>(&secret);
+    let (sig, _) = ecdsa::sign::<Config, D>(&secret, message.as_bytes()).unwrap();
+    let expected = [hex::decode(r).unwrap(), hex::decode(s).unwrap()].concat();
+    assert_eq!(sig.to_bytes(), expected, "{}", message);
+    assert!(ecdsa::verify::<Config, D>(&public, message.as_bytes(), &sig).is_ok());
+}
+
+#[test]
+fn test_ecdsa_sign_verify() {
+    ecdsa_test::<Config, Sha256>();
+}
+
+/// Checks the Wycheproof vectors vendored by the RustCrypto `p256` crate; see
+/// the header of the file for the cases left out.
+#[test]
+fn test_ecdsa_wycheproof() {
+    wycheproof_ecdsa_test::<Config, Sha256>(
+        "./src/curves/tests/ecdsa_secp256r1_sha256_p1363_test.json",
+    );
+}
+
+/// The P-256 vectors of RFC 6979, appendix A.2.5, with SHA-256 and SHA-512.
+#[test]
+fn test_ecdsa_rfc6979() {
+    let secret = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    let public =
+        ecdsa::public_key::<Config>(&Fr::from_be_bytes_mod_order(&hex::decode(secret).unwrap()));
+    assert_eq!(
+        sec1::encode_point(&public, false),
+        hex::decode(
+            "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+             7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+        )
+        .unwrap()
+    );
+    check_rfc6979::<Sha256>(
+        secret,
+        "sample",
+        "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+        "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+    );
+    check_rfc6979::<Sha256>(
+        secret,
+        "test",
+        "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+        "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+    );
+    check_rfc6979::<Sha512>(
+        secret,
+        "sample",
+        "8496a60b5e9b47c825488827e0495b0e3fa109ec4568fd3f8d1097678eb97f00",
+        "2362ab1adbe2b8adf9cb9edab740ea6049c028114f2460f96554f61fae3302fe",
+    );
+}
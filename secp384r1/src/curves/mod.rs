@@ -6,6 +6,8 @@ use ark_ff::{Field, MontFp};
 
 use crate::{fq::Fq, fr::Fr};
 
+pub use ark_curve_utils::{ecdsa, sec1};
+
 #[cfg(test)]
 mod tests;
 
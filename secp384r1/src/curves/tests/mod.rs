@@ -0,0 +1,109 @@
+use crate::{ecdsa, sec1, Config, Fr, Projective};
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::{
+    ecdsa::{ecdsa_test, wycheproof_ecdsa_test},
+    sec1::{sec1_generator_multiples_test, sec1_test},
+};
+use ark_ff::PrimeField;
+use sha2::{digest::core_api::BlockSizeUser, Digest, Sha256, Sha384, Sha512};
+
+test_group!(g1; Projective; sw);
+
+/// Checks the SEC 1 encodings against the multiples of the generator of
+/// the NIST P-384 test vectors of <http://point-at-infinity.org/ecc/nisttv>,
+/// as vendored by the RustCrypto `p384` crate.
+#[test]
+fn test_sec1_generator_multiples() {
+    sec1_generator_multiples_test::<Config>("./src/curves/tests/sec1_generator_multiples.json");
+}
+
+/// Checks the SEC 1 point decoding against the P-384 public key validation
+/// tests of `ring` and NIST CAVS; see the header of the file for the cases
+/// derived from them.
+#[test]
+fn test_sec1() {
+    sec1_test::<Config>("./src/curves/tests/sec1_public_key_test.json");
+}
+
+/// Checks a signature of RFC 6979, appendix A.2.
+fn check_rfc6979<D: Digest + BlockSizeUser>(secret: &str, message: &str, r: &str, s: &str) {
+    let secret = Fr::from_be_bytes_mod_order(&hex::decode(secret).unwrap());
+    let public = ecdsa::public_key::<Config>(&secret);
+    let (sig, _) = ecdsa::sign::<Config, D>(&secret, message.as_bytes()).unwrap();
+    let expected = [hex::decode(r).unwrap(), hex::decode(s).unwrap()].concat();
+    assert_eq!(sig.to_bytes(), expected, "{}", message);
+    assert!(ecdsa::verify::<Config, D>(&public, message.as_bytes(), &sig).is_ok());
+}
+
+#[test]
+fn test_ecdsa_sign_verify() {
+    ecdsa_test::<Config, Sha384>();
+}
+
+/// Checks the Wycheproof vectors vendored by the RustCrypto `p384` crate; see
+/// the header of the file for the cases left out.
+#[test]
+fn test_ecdsa_wycheproof() {
+    wycheproof_ecdsa_test::<Config, Sha384>(
+        "./src/curves/tests/ecdsa_secp384r1_sha384_p1363_test.json",
+    );
+}
+
+/// The P-384 vectors of RFC 6979, appendix A.2.6, with SHA-256, SHA-384 and
+/// SHA-512.
+#[test]
+fn test_ecdsa_rfc6979() {
+    let secret = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8\
+                  96d5724e4c70a825f872c9ea60d2edf5";
+    let public =
+        ecdsa::public_key::<Config>(&Fr::from_be_bytes_mod_order(&hex::decode(secret).unwrap()));
+    assert_eq!(
+        sec1::encode_point(&public, false),
+        hex::decode(
+            "04ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64\
+             def8f0ea9055866064a254515480bc138015d9b72d7d57244ea8ef9ac0c62189\
+             6708a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720"
+        )
+        .unwrap()
+    );
+    check_rfc6979::<Sha256>(
+        secret,
+        "sample",
+        "21b13d1e013c7fa1392d03c5f99af8b30c570c6f98d4ea8e354b63a21d3daa33\
+         bde1e888e63355d92fa2b3c36d8fb2cd",
+        "f3aa443fb107745bf4bd77cb3891674632068a10ca67e3d45db2266fa7d1feeb\
+         efdc63eccd1ac42ec0cb8668a4fa0ab0",
+    );
+    check_rfc6979::<Sha256>(
+        secret,
+        "test",
+        "6d6defac9ab64dabafe36c6bf510352a4cc27001263638e5b16d9bb51d451559\
+         f918eedaf2293be5b475cc8f0188636b",
+        "2d46f3becbcc523d5f1a1256bf0c9b024d879ba9e838144c8ba6baeb4b53b47d\
+         51ab373f9845c0514eefb14024787265",
+    );
+    check_rfc6979::<Sha384>(
+        secret,
+        "sample",
+        "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c\
+         81a648152e44acf96e36dd1e80fabe46",
+        "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94f\
+         a329c145786e679e7b82c71a38628ac8",
+    );
+    check_rfc6979::<Sha384>(
+        secret,
+        "test",
+        "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb\
+         0542a7f0812998da8f1dd3ca3cf023db",
+        "ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e0\
+         6a739f040649a667bf3b828246baa5a5",
+    );
+    check_rfc6979::<Sha512>(
+        secret,
+        "sample",
+        "ed0959d5880ab2d869ae7f6c2915c6d60f96507f9cb3e047c0046861da4a799c\
+         fe30f35cc900056d7c99cd7882433709",
+        "512c8cceee3890a84058ce1e22dbc2198f42323ce8aca9135329f03c068e5112\
+         dc7cc3ef3446defceb01a45c2667fdd5",
+    );
+}
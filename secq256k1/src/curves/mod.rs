@@ -1,8 +1,9 @@
 use ark_ec::{
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -41,6 +42,48 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+}
+
+impl GLVConfig for Config {
+    /// BETA, a non-trivial cube root of unity in Fq. The endomorphism
+    /// (x, y) -> (BETA * x, y) acts on the points as multiplication by LAMBDA.
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "37718080363155996902926221483475020450927657555482586988616620542887997980018"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("55594575648329892869085402983802832744385952214688224221778511981742606582254");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709078")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468249")),
+        (true, BigInt!("64502973549206556628585045361533709078")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X =
@@ -1,10 +1,18 @@
 use crate::{fq::Fq, fr::Fr};
+use ark_curve_utils::field_hasher::XmdFieldHasher;
 use ark_ec::{
+    hashing::{
+        curve_maps::wb::{IsogenyMap, WBConfig, WBMap},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+    },
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
+use blake2::Blake2b512;
+
+mod swu_iso;
 
 #[cfg(test)]
 mod tests;
@@ -26,6 +34,18 @@ impl CurveConfig for VestaConfig {
 pub type Affine = sw::Affine<VestaConfig>;
 pub type Projective = sw::Projective<VestaConfig>;
 
+/// `hash_to_field` of the `vesta_XMD:BLAKE2b_SSWU_RO_` suite, which uses
+/// BLAKE2b-512 and 64 bytes per field element.
+pub type FieldHasher = XmdFieldHasher<Blake2b512, 256>;
+
+/// Hashes to the curve as the `GroupHash` of the Zcash protocol
+/// specification, section 5.4.9.8, and `pasta_curves`, when passed the DST
+/// `domain_prefix || "-" || SUITE_ID`.
+pub type Hasher = MapToCurveBasedHasher<Projective, FieldHasher, WBMap<VestaConfig>>;
+
+/// The suite ID of the random-oracle suite computed by [`Hasher`].
+pub const SUITE_ID: &str = "vesta_XMD:BLAKE2b_SSWU_RO_";
+
 impl SWCurveConfig for VestaConfig {
     /// COEFF_A = 0
     const COEFF_A: Fq = Fq::ZERO;
@@ -42,6 +62,15 @@ impl SWCurveConfig for VestaConfig {
     }
 }
 
+// Parameters of the `hash_to_curve` of [pasta_curves](https://github.com/zcash/pasta_curves),
+// used by Zcash Orchard: the simplified SWU map to iso-Vesta followed by a 3-isogeny.
+impl WBConfig for VestaConfig {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_VESTA;
+}
+
 impl GLVConfig for VestaConfig {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
         "26005156700822196841419187675678338661165322343552424574062261873906994770353"
@@ -0,0 +1,101 @@
+use ark_ec::{
+    hashing::curve_maps::{swu::SWUConfig, wb::IsogenyMap},
+    models::{
+        short_weierstrass::{Affine, SWCurveConfig},
+        CurveConfig,
+    },
+};
+use ark_ff::{Field, MontFp};
+
+use crate::{fq::Fq, fr::Fr};
+
+type IsoAffine = Affine<SwuIsoConfig>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct SwuIsoConfig;
+
+impl CurveConfig for SwuIsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+// https://zips.z.cash/protocol/protocol.pdf
+// Zcash Protocol Specification
+// 5.4.9.8  Group Hash into Pallas and Vesta
+// iso-Vesta is defined as follows:
+// * E': y'^2 = x'^3 + A' * x' + B', where
+//      -  A' = 0x267f9b2ee592271a81639c4d96f787739673928c7d01b212c515ad7242eaa6b1
+//            = 17413348858408915339762682399132325137863850198379221683097628341577494210225
+//      -  B' = 1265
+//  * Z: -13
+impl SWCurveConfig for SwuIsoConfig {
+    const COEFF_A: Fq =
+        MontFp!("17413348858408915339762682399132325137863850198379221683097628341577494210225");
+
+    const COEFF_B: Fq = MontFp!("1265");
+
+    const GENERATOR: IsoAffine = IsoAffine::new_unchecked(ISO_GENERATOR_X, ISO_GENERATOR_Y);
+}
+
+/// Lexicographically smallest, valid x-coordinate of a point P on the curve
+/// together with the smaller of its two y-coordinates. Since the cofactor is
+/// 1, P generates the whole group.
+/// P_x = 4
+/// P_y = 2165270085553270387583265107994083524758817942147891525126107618954199130179
+const ISO_GENERATOR_X: Fq = MontFp!("4");
+const ISO_GENERATOR_Y: Fq =
+    MontFp!("2165270085553270387583265107994083524758817942147891525126107618954199130179");
+
+impl SWUConfig for SwuIsoConfig {
+    // ZETA = -13 as per the Zcash protocol specification.
+    const ZETA: Fq = MontFp!("-13");
+}
+
+// Coefficients of the 3-isogeny from iso-Vesta to Vesta, as in
+// `ISOGENY_CONSTANTS` of [pasta_curves](https://github.com/zcash/pasta_curves).
+pub const ISOGENY_MAP_TO_VESTA: IsogenyMap<'_, SwuIsoConfig, crate::VestaConfig> = IsogenyMap {
+    x_map_numerator: &[
+        MontFp!("22515128462811482443472135973911537638171266152621281295306466582083726737451"),
+        MontFp!("11064082577423419940183149293632076317553812518550871517841037420579891210813"),
+        MontFp!("13377367003779316331268047403600734872799183885837485433911493934102207511749"),
+        MontFp!("25731575386070265649682441113041757300767161317281464337493104665238544842753"),
+    ],
+
+    x_map_denominator: &[
+        MontFp!("9250006497141849826017568406346290940322373181457057184910582871723433210981"),
+        MontFp!("4604213796697651557841441623718706001740429044770779386484474413346415813353"),
+        MontFp!("1"),
+    ],
+
+    y_map_numerator: &[
+        MontFp!("13937936667454727226911322269564285204582212380194126516142098360337545123123"),
+        MontFp!("11620280474556824258112134491145636201000922752744881519070727793732904824884"),
+        MontFp!("21162694656554182593580396827886355918081120183889566406795618341247785229923"),
+        MontFp!("8577191795356755216560813704347252433589053772427154779164368221746181614251"),
+    ],
+
+    y_map_denominator: &[
+        MontFp!("28948022309329048855892746252171976963363056481941647379679742748393362947557"),
+        MontFp!("27750019491425549478052705219038872820967119544371171554731748615170299632943"),
+        MontFp!("21380331849711001764708535561664047484292171808126992769566582994216305194078"),
+        MontFp!("1"),
+    ],
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gen() {
+        let gen: IsoAffine = SwuIsoConfig::GENERATOR;
+        assert!(gen.is_on_curve());
+        assert!(gen.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
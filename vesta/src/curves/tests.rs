@@ -1,5 +0,0 @@
-use crate::Projective;
-use ark_algebra_test_templates::*;
-
-test_group!(g1; Projective; sw);
-test_group!(g1_glv; Projective; glv);
@@ -0,0 +1,38 @@
+use crate::{Affine, FieldHasher, Fq, Hasher, Projective, VestaConfig, SUITE_ID};
+use ark_algebra_test_templates::*;
+use ark_curve_constraint_tests::hashing::hash_to_curve_test;
+use ark_ec::hashing::{curve_maps::wb::WBMap, HashToCurve};
+use ark_ff::{Field, PrimeField};
+
+test_group!(g1; Projective; sw);
+test_group!(g1_glv; Projective; glv);
+
+fn read_point(x: Fq, y: Fq) -> Affine {
+    let p = Affine::new_unchecked(x, y);
+    assert!(p.is_on_curve());
+    p
+}
+
+#[test]
+fn test_hash_to_curve() {
+    hash_to_curve_test::<Projective, FieldHasher, WBMap<VestaConfig>>(
+        "./src/curves/tests/vesta_XMD-BLAKE2b_SSWU_RO_.json",
+        read_point,
+    );
+}
+
+/// The `hash_to_curve` test vector of pasta_curves, which is given in
+/// Jacobian coordinates (x, y, z), i.e. the affine point (x / z^2, y / z^3).
+#[test]
+fn test_hash_to_curve_pasta_curves() {
+    let read_fq = |hex_str: &str| Fq::from_be_bytes_mod_order(&hex::decode(hex_str).unwrap());
+    let x = read_fq("12763505036e0e1a6684b7a7d8d5afb7378cc2b191a95e34f44824a06fcbd08e");
+    let y = read_fq("0256eafc0188b79bfa7c4b2b393893ddc298e90da500fa4a9aee17c2ea4240e6");
+    let z = read_fq("1b58d4aa4d68c3f4d9916b77c79ff9911597a27f2ee46244e98eb9615172d2ad");
+    let z_inv = z.inverse().unwrap();
+    let expected = Affine::new(x * z_inv.square(), y * z_inv.square() * z_inv);
+
+    let dst = ["z.cash:test-", SUITE_ID].concat();
+    let hasher = Hasher::new(dst.as_bytes()).unwrap();
+    assert_eq!(hasher.hash(b"hello").unwrap(), expected);
+}